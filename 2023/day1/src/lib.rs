@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+const INT_STR_MAP: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn find_digit_substrs(s: &str, spelled_out: bool) -> Vec<(usize, i32)> {
+
+    let mut digits: Vec<(usize, i32)> = Vec::new();
+    
+    // Search for digits
+    for i in 0..10 {
+        let int_str = i.to_string();
+        let occurences = s.match_indices(int_str.as_str());
+
+        let mut d = occurences.map(|occ| (occ.0, occ.1.parse::<i32>().expect(""))).collect::<Vec<(usize, i32)>>();
+
+        digits.append(&mut d);
+    }
+
+    if !spelled_out {
+        return digits;
+    }
+
+    // Search for textual numbers
+    for (i, int_str) in INT_STR_MAP.iter().enumerate() {
+
+        let occurences = s.match_indices(int_str);
+        let mut d = occurences.map(|occ| (occ.0, i as i32)).collect::<Vec<(usize, i32)>>();
+
+        digits.append(&mut d);
+    }
+
+    return digits;
+}
+
+/// Two-digit number from the first and last digits of the line, or None if it has no digits
+fn parse_line(s: &str, spelled_out: bool) -> Option<i32> {
+
+    let mut digits = find_digit_substrs(s, spelled_out);
+    digits.sort_by_key(|x| x.0);
+
+    let first = digits.first()?.1;
+    let last = digits.last()?.1;
+
+    return Some(first * 10 + last);
+}
+
+fn sum_lines(lines: &[String], spelled_out: bool) -> Result<i32, String> {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        sum += parse_line(line, spelled_out)
+            .ok_or_else(|| format!("Line {} has no numeric digits", i + 1))?;
+    }
+
+    return Ok(sum);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines: Vec<String> = Vec::new();
+
+        for line in aoc_common::numbered_lines(input) {
+            if parse_line(&line, true).is_none() {
+                return Err(line.error(line.text, "Line has no digits"));
+            }
+            lines.push(line.to_string());
+        }
+
+        return Ok(lines);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return sum_lines(input, false);
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return sum_lines(input, true);
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt::Display;
+use std::ops::Deref;
+
+use aoc_common::grid::Position;
+use aoc_common::{Grid, ParseError, Solution};
+
+const START_CHAR: char = 'S';
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    fn opposite(&self) -> Direction {
+        match self {
+            Direction::N => Direction::S,
+            Direction::E => Direction::W,
+            Direction::S => Direction::N,
+            Direction::W => Direction::E,
+        }
+    }
+
+    /// (row, col) offset of one step in this direction
+    fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::N => (-1, 0),
+            Direction::E => (0, 1),
+            Direction::S => (1, 0),
+            Direction::W => (0, -1),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Tile {
+    raw_char: char,
+    pipe: Option<(Direction, Direction)>,
+}
+
+impl TryFrom<&char> for Tile {
+    type Error = String;
+
+    fn try_from(c: &char) -> Result<Self, Self::Error> {
+        Ok(Tile {
+            raw_char: *c,
+            pipe: Tile::build_pipe(c)?,
+        })
+    }
+}
+
+impl Tile {
+    fn build_pipe(
+        c: &char,
+    ) -> Result<Option<(Direction, Direction)>, <Tile as TryFrom<&char>>::Error> {
+        match c {
+            '|' => Ok(Some((Direction::N, Direction::S))),
+            '-' => Ok(Some((Direction::E, Direction::W))),
+            'L' => Ok(Some((Direction::N, Direction::E))),
+            'J' => Ok(Some((Direction::N, Direction::W))),
+            '7' => Ok(Some((Direction::S, Direction::W))),
+            'F' => Ok(Some((Direction::S, Direction::E))),
+            '.' => Ok(None),
+            'S' => Ok(None),
+            _ => Err(format!("Unrecognized pipe character: {}", c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map(Grid<Tile>);
+
+impl Deref for Map {
+    type Target = Grid<Tile>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(contents: &str) -> Result<Self, Self::Error> {
+        return Ok(Map(Grid::parse(contents, |c| Tile::try_from(&c))?));
+    }
+}
+
+impl Map {
+    fn find_start(&self) -> Result<Position, String> {
+        for (pos, tile) in self.iter() {
+            if tile.raw_char == START_CHAR {
+                return Ok(pos);
+            }
+        }
+        Err("Could not find start position".to_owned())
+    }
+
+    fn step(&self, start: &Position, entered_from_dir: &Direction) -> Result<Position, String> {
+        let tile = self
+            .get(*start)
+            .ok_or_else(|| format!("No position {:?}", start))?;
+
+        let pipe = tile
+            .pipe
+            .as_ref()
+            .ok_or_else(|| format!("Position {:?} has no pipe: \"{}\"", start, tile.raw_char))?;
+
+        if &pipe.0 != entered_from_dir && &pipe.1 != entered_from_dir {
+            return Err(format!(
+                "Can't have entered pipe {:?} from {:?}",
+                pipe, entered_from_dir
+            ));
+        }
+
+        let dir_to_step: Direction = match entered_from_dir {
+            _ if entered_from_dir == &pipe.0 => pipe.1,
+            _ if entered_from_dir == &pipe.1 => pipe.0,
+            _ => {
+                return Err(format!(
+                    "Entered from a direction ({:?}) not supported by the pipe {:?}",
+                    entered_from_dir, pipe
+                ))
+            }
+        };
+
+        return self
+            .neighbour(start, &dir_to_step)
+            .ok_or_else(|| format!("Pipe at {:?} leads off the map", start));
+    }
+
+    /// Position one tile away from `pos` in direction `dir`, if it is on the map
+    fn neighbour(&self, pos: &Position, dir: &Direction) -> Option<Position> {
+        return self.offset(*pos, dir.delta());
+    }
+
+    /// Whether the tile one step from `pos` in direction `dir` has a pipe leading back to `pos`
+    fn connects(&self, pos: &Position, dir: &Direction) -> bool {
+        let back = dir.opposite();
+        return self
+            .neighbour(pos, dir)
+            .and_then(|next| self.get(next))
+            .and_then(|tile| tile.pipe.as_ref())
+            .is_some_and(|pipe| pipe.0 == back || pipe.1 == back);
+    }
+
+    /// Positions of every tile in the loop through the start, in walking order. Pipes that
+    /// connect to the start but lead nowhere are passed over.
+    fn find_loop(&self) -> Result<Vec<Position>, String> {
+        let start = self.find_start()?;
+        let mut error = "No pipe connects to the start position".to_owned();
+        for first_dir in Direction::ALL {
+            if !self.connects(&start, &first_dir) {
+                continue;
+            }
+            match self.walk(&start, &first_dir) {
+                Ok(path) => return Ok(path),
+                Err(e) => error = e,
+            }
+        }
+        return Err(error);
+    }
+
+    /// Positions of every tile passed by following the pipes from `start`, leaving it in
+    /// direction `first_dir`, until they lead back to it
+    fn walk(&self, start: &Position, first_dir: &Direction) -> Result<Vec<Position>, String> {
+        let start = *start;
+        let mut path: Vec<Position> = vec![start];
+        let mut current = self
+            .neighbour(&start, first_dir)
+            .expect("Connected tiles are on the map");
+        let mut entered_from_dir = first_dir.opposite();
+
+        while current != start {
+            let next = self.step(&current, &entered_from_dir)?;
+            entered_from_dir = Direction::ALL
+                .into_iter()
+                .find(|dir| self.neighbour(&next, dir) == Some(current))
+                .expect("Steps always move to an adjacent tile");
+
+            path.push(current);
+            current = next;
+        }
+
+        return Ok(path);
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Map::try_from(input);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        // The farthest tile is halfway around the loop
+        return Ok(input.find_loop()?.len() / 2);
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        let path = input.find_loop()?;
+
+        // Shoelace formula for the area enclosed by the loop...
+        let twice_area: isize = path
+            .iter()
+            .zip(path.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 as isize * b.1 as isize - a.1 as isize * b.0 as isize)
+            .sum();
+
+        // ...and Pick's theorem for the number of tiles strictly inside it
+        return Ok(twice_area.abs() / 2 - path.len() as isize / 2 + 1);
+    }
+}
@@ -0,0 +1,78 @@
+use day10::Day10;
+
+fn answers(input: &str) -> Vec<String> {
+    return aoc_common::solve::<Day10>(input, &[1, 2]).unwrap();
+}
+
+#[test]
+fn farthest_tile_of_the_examples() {
+    let simple = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+    assert_eq!(answers(simple)[0], "4");
+
+    let complex = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+    assert_eq!(answers(complex)[0], "8");
+}
+
+#[test]
+fn tiles_enclosed_by_the_examples() {
+    let squeezed = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+    assert_eq!(answers(squeezed)[1], "4");
+
+    let larger = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+    assert_eq!(answers(larger)[1], "8");
+}
+
+#[test]
+fn pipes_into_the_start_that_lead_nowhere_are_passed_over() {
+    // The pipe above S connects to it, but runs into ground
+    let input = "\
+.....
+.|...
+.S-7.
+.|.|.
+.L-J.
+";
+    assert_eq!(answers(input), vec!["4", "1"]);
+
+    // The same with the dead end leading off the map
+    assert_eq!(answers(&input[6..]), vec!["4", "1"]);
+}
+
+#[test]
+fn a_start_with_no_loop_is_an_error() {
+    assert!(aoc_common::solve::<Day10>("S-.\n", &[1]).is_err());
+    assert!(aoc_common::solve::<Day10>("...\n.S.\n...\n", &[1]).is_err());
+}
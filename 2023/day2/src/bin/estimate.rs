@@ -0,0 +1,99 @@
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day2::estimate::{estimate_bag, Estimate, Options};
+use day2::{CubeNums, Day2, Game};
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: estimate [--bag BAG_FILE] [--max N] [--level P] [INPUT]";
+
+fn describe(game: &Game, estimate: &Estimate) -> String {
+    let colours: Vec<String> = estimate
+        .likely
+        .iter()
+        .map(|(colour, count)| {
+            let bounds = estimate.bounds[colour];
+            let capped = estimate.capped.iter().any(|c| c == colour);
+            return format!(
+                "{} {}{} (min {}, {}..={}{})",
+                colour,
+                count,
+                if capped { "+" } else { "" },
+                estimate.min.get(colour),
+                bounds.low,
+                bounds.high,
+                if bounds.capped { "+" } else { "" }
+            );
+        })
+        .collect();
+    return format!("Game {}: {}", game.num, colours.join(", "));
+}
+
+fn run(path: &str, bag: Option<&str>, options: &Options) -> Result<(), String> {
+    let bag = match bag {
+        Some(file) => CubeNums::load_bag(file)?,
+        None => CubeNums::parse_bag(day2::BAG).map_err(|e| e.to_string())?,
+    };
+    let contents = aoc_common::load_input(path)?;
+    let games = Day2::parse(&contents).map_err(|e| e.with_file(path).to_string())?;
+
+    let (mut min_total, mut likely_total) = (0, 0);
+    for game in &games {
+        let estimate = estimate_bag(game, &bag, options)?;
+        println!("{}", describe(game, &estimate));
+        min_total += estimate.min.iter().map(|(_, n)| n).sum::<i32>();
+        likely_total += estimate.likely.iter().map(|(_, n)| n).sum::<i32>();
+    }
+
+    println!();
+    println!(
+        "{} games: {} cubes in the smallest bags, {} in the most likely ones ({:.0}% confidence bounds, + means unbounded up to {})",
+        games.len(),
+        min_total,
+        likely_total,
+        options.level * 100.0,
+        options.max_per_colour
+    );
+    return Ok(());
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{}", USAGE);
+    return ExitCode::FAILURE;
+}
+
+/// Prints each game's most likely bag next to the smallest possible one
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut bag = None;
+    let mut options = Options::default();
+    let mut path = INPUT_FILE.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => match args.next() {
+                Some(file) => bag = Some(file),
+                None => return usage(),
+            },
+            "--max" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) => options.max_per_colour = n,
+                None => return usage(),
+            },
+            "--level" => match args.next().and_then(|p| p.parse().ok()) {
+                Some(p) => options.level = p,
+                None => return usage(),
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => path = arg,
+        }
+    }
+
+    if let Err(e) = run(&path, bag.as_deref(), &options) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
@@ -0,0 +1,50 @@
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day2::query::Query;
+use day2::Day2;
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: query QUERY [INPUT]
+e.g. query 'max(red) > 10 && any_round(blue == 0)'";
+
+fn run(query: &str, path: &str) -> Result<(), String> {
+    let query = Query::parse(query).map_err(|e| e.with_file("<query>").to_string())?;
+    let contents = aoc_common::load_input(path)?;
+    let games = Day2::parse(&contents).map_err(|e| e.with_file(path).to_string())?;
+
+    let matches = query.run(&games);
+    let numbers: Vec<String> = matches.games.iter().map(|n| n.to_string()).collect();
+    println!("Games: {}", numbers.join(", "));
+    println!(
+        "{} of {} games match, their numbers sum to {}",
+        matches.games.len(),
+        matches.of,
+        matches.sum
+    );
+    return Ok(());
+}
+
+/// Lists the games a query matches
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let (query, path) = match args.as_slice() {
+        [query] => (query, INPUT_FILE),
+        [query, path] => (query, path.as_str()),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = run(query, path) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use crate::{min_cube_counts, CubeNums, Game};
+
+/// Largest `max_per_colour` accepted. The search takes time roughly cubic in the limit:
+/// at this one, a whole puzzle input takes a couple of seconds.
+pub const MAX_PER_COLOUR_LIMIT: i32 = 200;
+
+/// How an estimate is searched for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    /// Most cubes of one colour the bag is assumed to hold, at most `MAX_PER_COLOUR_LIMIT`
+    pub max_per_colour: i32,
+    /// Coverage of the confidence bounds, e.g. 0.95
+    pub level: f64,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        return Options {
+            max_per_colour: 100,
+            level: 0.95,
+        };
+    }
+}
+
+/// Likelihood-based range of plausible counts for one colour, inclusive at both ends
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub low: i32,
+    pub high: i32,
+    /// `high` is `max_per_colour`, so the data doesn't bound the count from above
+    pub capped: bool,
+}
+
+/// The most likely contents of the bag that played a game
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    /// The smallest bag that could have played the game
+    pub min: CubeNums,
+    /// The bag under which the game's draws are most likely
+    pub likely: CubeNums,
+    /// Colours whose count in `likely` is `max_per_colour`. The likelihood may still rise
+    /// past it, so the count only says the bag holds at least that many.
+    pub capped: Vec<String>,
+    pub bounds: BTreeMap<String, Bounds>,
+    /// Natural log of the likelihood of `likely`
+    pub log_likelihood: f64,
+}
+
+/// Log-likelihood of a game's rounds under a bag, where each round draws its cubes
+/// without replacement and the cubes go back in between rounds
+struct Model {
+    colours: Vec<String>,
+    /// Per round, the number drawn of each colour in `colours`
+    rounds: Vec<Vec<i32>>,
+    /// ln(n!) for n up to the largest possible bag
+    ln_factorial: Vec<f64>,
+}
+
+impl Model {
+    fn new(game: &Game, colours: Vec<String>, max_total: usize) -> Model {
+        let rounds = game
+            .rounds
+            .iter()
+            .map(|round| colours.iter().map(|c| round.get(c)).collect())
+            .collect();
+
+        let mut ln_factorial = vec![0.0; max_total + 1];
+        for n in 1..=max_total {
+            ln_factorial[n] = ln_factorial[n - 1] + (n as f64).ln();
+        }
+
+        return Model {
+            colours,
+            rounds,
+            ln_factorial,
+        };
+    }
+
+    fn ln_choose(&self, n: i32, k: i32) -> f64 {
+        let (n, k) = (n as usize, k as usize);
+        return self.ln_factorial[n] - self.ln_factorial[k] - self.ln_factorial[n - k];
+    }
+
+    /// The multivariate hypergeometric log-likelihood of every round under `bag`
+    fn log_likelihood(&self, bag: &[i32]) -> f64 {
+        let total: i32 = bag.iter().sum();
+        let mut ll = 0.0;
+        for round in &self.rounds {
+            let drawn: i32 = round.iter().sum();
+            if round.iter().zip(bag).any(|(x, k)| x > k) {
+                return f64::NEG_INFINITY;
+            }
+            ll += round
+                .iter()
+                .zip(bag)
+                .map(|(&x, &k)| self.ln_choose(k, x))
+                .sum::<f64>();
+            ll -= self.ln_choose(total, drawn);
+        }
+        return ll;
+    }
+
+    /// Improves `bag` one colour at a time, leaving colour `fixed` alone, until no single
+    /// colour can be changed for the better. Ties keep the current count. Returns the
+    /// log-likelihood of the result.
+    fn maximise(&self, bag: &mut [i32], min: &[i32], max: i32, fixed: Option<usize>) -> f64 {
+        let mut best = self.log_likelihood(bag);
+        loop {
+            let mut improved = false;
+            for c in 0..bag.len() {
+                if Some(c) == fixed {
+                    continue;
+                }
+
+                let mut best_k = bag[c];
+                for k in min[c]..=max {
+                    bag[c] = k;
+                    let ll = self.log_likelihood(bag);
+                    if ll > best + 1e-12 {
+                        best = ll;
+                        best_k = k;
+                        improved = true;
+                    }
+                }
+                bag[c] = best_k;
+            }
+            if !improved {
+                return best;
+            }
+        }
+    }
+}
+
+/// The quantile function of the standard normal distribution, by Acklam's rational
+/// approximation (relative error below 1.2e-9)
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+
+    let tail = |q: f64| {
+        let num = ((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5];
+        let den = (((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0;
+        return num / den;
+    };
+
+    if p < 0.02425 {
+        return tail((-2.0 * p.ln()).sqrt());
+    }
+    if p > 1.0 - 0.02425 {
+        return -tail((-2.0 * (1.0 - p).ln()).sqrt());
+    }
+    let q = p - 0.5;
+    let r = q * q;
+    let num = ((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5];
+    let den = ((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0;
+    return q * num / den;
+}
+
+/// Estimates the bag that played `game`, for every colour in `bag` and every colour the
+/// game drew.
+///
+/// Each round is a draw without replacement, so a round's counts follow a multivariate
+/// hypergeometric distribution. The estimate maximises the product of the rounds'
+/// likelihoods, searching one colour at a time, which finds a local maximum. The bounds
+/// for a colour are the counts whose profile likelihood is within the chi-squared cut-off
+/// for `options.level` of the maximum.
+pub fn estimate_bag(game: &Game, bag: &CubeNums, options: &Options) -> Result<Estimate, String> {
+    if !(options.level > 0.0 && options.level < 1.0) {
+        return Err(format!(
+            "Confidence level must be between 0 and 1, not {}",
+            options.level
+        ));
+    }
+
+    if !(0..=MAX_PER_COLOUR_LIMIT).contains(&options.max_per_colour) {
+        return Err(format!(
+            "The limit per colour must be between 0 and {}, not {}",
+            MAX_PER_COLOUR_LIMIT, options.max_per_colour
+        ));
+    }
+    for round in &game.rounds {
+        if let Some((colour, count)) = round.iter().find(|&(_, n)| n < 0) {
+            return Err(format!("Game {} drew {} {}", game.num, count, colour));
+        }
+    }
+
+    let min = min_cube_counts(game, bag);
+    let colours: Vec<String> = min.colours().map(|c| c.to_string()).collect();
+    let min_counts: Vec<i32> = colours.iter().map(|c| min.get(c)).collect();
+    let max = options.max_per_colour;
+    if let Some((colour, &count)) = colours.iter().zip(&min_counts).find(|(_, &n)| n > max) {
+        return Err(format!(
+            "Game {} drew {} {}, more than the limit of {} per colour",
+            game.num, count, colour, max
+        ));
+    }
+
+    let model = Model::new(game, colours, max as usize * min_counts.len());
+    let mut likely = min_counts.clone();
+    let log_likelihood = model.maximise(&mut likely, &min_counts, max, None);
+
+    // Likelihood-ratio test with one degree of freedom
+    let z = normal_quantile((1.0 + options.level) / 2.0);
+    let cutoff = log_likelihood - z * z / 2.0;
+    let plausible = |c: usize, k: i32| {
+        let mut profile = likely.clone();
+        profile[c] = k;
+        return model.maximise(&mut profile, &min_counts, max, Some(c)) >= cutoff;
+    };
+
+    let mut bounds = BTreeMap::new();
+    for (c, colour) in model.colours.iter().enumerate() {
+        let mut low = likely[c];
+        while low > min_counts[c] && plausible(c, low - 1) {
+            low -= 1;
+        }
+        let mut high = likely[c];
+        while high < max && plausible(c, high + 1) {
+            high += 1;
+        }
+
+        let capped = high == max;
+        bounds.insert(colour.clone(), Bounds { low, high, capped });
+    }
+
+    let mut estimate = CubeNums::new();
+    let mut capped = Vec::new();
+    for (colour, &count) in model.colours.iter().zip(&likely) {
+        estimate.set(colour, count);
+        if count == max {
+            capped.push(colour.clone());
+        }
+    }
+    return Ok(Estimate {
+        min,
+        likely: estimate,
+        capped,
+        bounds,
+        log_likelihood,
+    });
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use aoc_common::{Line, ParseError, Solution};
+
+pub mod estimate;
+pub mod query;
+
+/// The bag from the puzzle, used by `Day2`
+pub const BAG: &str = include_str!("../bag.txt");
+
+/// Numbers of cubes by colour. Colours that aren't listed have no cubes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CubeNums {
+    counts: BTreeMap<String, i32>,
+}
+
+impl CubeNums {
+    pub fn new() -> CubeNums {
+        return CubeNums::default();
+    }
+
+    pub fn get(&self, colour: &str) -> i32 {
+        return self.counts.get(colour).copied().unwrap_or(0);
+    }
+
+    pub fn set(&mut self, colour: &str, num: i32) {
+        self.counts.insert(colour.to_string(), num);
+    }
+
+    /// Every listed colour with its number, in alphabetical order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i32)> + '_ {
+        return self.counts.iter().map(|(colour, &num)| (colour.as_str(), num));
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> + '_ {
+        return self.counts.keys().map(|colour| colour.as_str());
+    }
+
+    /// The numbers of every listed colour multiplied together, or None if that overflows
+    /// u64 or a number is negative
+    pub fn power(&self) -> Option<u64> {
+        let mut power: u64 = 1;
+        for &num in self.counts.values() {
+            power = power.checked_mul(u64::try_from(num).ok()?)?;
+        }
+        return Some(power);
+    }
+
+    /// Parses a bag configuration: one `colour = count` per line, with blank lines and
+    /// `#` comments ignored
+    pub fn parse_bag(input: &str) -> Result<CubeNums, ParseError> {
+        let mut bag = CubeNums::new();
+        for line in aoc_common::numbered_lines(input) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let (colour, num) = line.split_once(text, "=")?;
+            let colour = parse_colour(&line, colour.trim())?;
+            if bag.counts.contains_key(colour) {
+                return Err(line.error(colour, format!("\"{}\" is listed twice", colour)));
+            }
+            bag.set(colour, parse_count(&line, num.trim())?);
+        }
+
+        if bag.counts.is_empty() {
+            return Err(ParseError::at_end(input, "Expected at least one colour"));
+        }
+        return Ok(bag);
+    }
+
+    /// Reads a bag configuration file, see `parse_bag`
+    pub fn load_bag(path: &str) -> Result<CubeNums, String> {
+        let contents = aoc_common::load_input(path)?;
+        return CubeNums::parse_bag(&contents).map_err(|e| e.with_file(path).to_string());
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub num: i32,
+    pub rounds: Vec<CubeNums>,
+}
+
+fn parse_colour<'a>(line: &Line<'a>, colour: &'a str) -> Result<&'a str, ParseError> {
+    if colour.is_empty() || !colour.chars().all(|c| c.is_alphabetic()) {
+        return Err(line.error(colour, format!("Expected a colour, found \"{}\"", colour)));
+    }
+    return Ok(colour);
+}
+
+/// A number of cubes, which can't be negative
+fn parse_count(line: &Line, num: &str) -> Result<i32, ParseError> {
+    let count = line.parse::<i32>(num)?;
+    if count < 0 {
+        return Err(line.error(num, "Number of cubes can't be negative"));
+    }
+    return Ok(count);
+}
+
+fn parse_round<'a>(line: &Line<'a>, s: &'a str) -> Result<CubeNums, ParseError> {
+    // Takes a string of the form "x red, y green, z blue"
+    // may be any colours in any order
+
+    let splits = s.split(", ");
+
+    let mut round = CubeNums::new();
+
+    for split in splits {
+        let (num, colour) = line.split_once(split, " ")?;
+        let num = parse_count(line, num)?;
+        let colour = parse_colour(line, colour)?;
+
+        if round.counts.contains_key(colour) {
+            return Err(line.error(colour, format!("\"{}\" is drawn twice in one round", colour)));
+        }
+        round.set(colour, num);
+    }
+
+    return Ok(round);
+}
+
+fn parse_game(line: &Line) -> Result<Game, ParseError> {
+
+    let (game, rounds) = line.split_once(line.text, ": ")?;
+
+    let game_num = line.parse::<i32>(line.strip_prefix(game, "Game ")?)?;
+    let rounds: Vec<CubeNums> = rounds
+        .split("; ")
+        .map(|s| parse_round(line, s))
+        .collect::<Result<_, _>>()?;
+
+    return Ok(Game{
+        num: game_num,
+        rounds,
+    });
+}
+
+/// The game's number, if none of its rounds drew more cubes of a colour than `bag` holds
+pub fn game_possible(game: &Game, bag: &CubeNums) -> Option<i32> {
+
+    for round in &game.rounds {
+        if round.iter().any(|(colour, num)| num > bag.get(colour)) { return None };
+    }
+
+    return Some(game.num);
+}
+
+/// The fewest cubes of each colour that could have played `game`: every colour in `bag`
+/// and every colour the game drew
+pub fn min_cube_counts(game: &Game, bag: &CubeNums) -> CubeNums {
+    let mut min = CubeNums::new();
+    for colour in bag.colours() {
+        min.set(colour, 0);
+    }
+
+    for (colour, num) in game.rounds.iter().flat_map(|r| r.iter()) {
+        if num > min.get(colour) {
+            min.set(colour, num);
+        }
+    }
+    return min;
+}
+
+/// Sum of the numbers of the games `bag` could have played
+pub fn possible_total(games: &[Game], bag: &CubeNums) -> i32 {
+    return games.iter().filter_map(|g| game_possible(g, bag)).sum();
+}
+
+/// Sum of the powers of each game's smallest bag
+pub fn power_total(games: &[Game], bag: &CubeNums) -> Result<u64, String> {
+    let mut total: u64 = 0;
+    for game in games {
+        let power = min_cube_counts(game, bag)
+            .power()
+            .ok_or_else(|| format!("Power of game {} overflows u64", game.num))?;
+        total = total
+            .checked_add(power)
+            .ok_or_else(|| format!("Sum of the powers up to game {} overflows u64", game.num))?;
+    }
+    return Ok(total);
+}
+
+fn puzzle_bag() -> Result<CubeNums, String> {
+    return CubeNums::parse_bag(BAG).map_err(|e| e.with_file("bag.txt").to_string());
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return aoc_common::numbered_lines(input).iter().map(parse_game).collect();
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(possible_total(input, &puzzle_bag()?));
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return power_total(input, &puzzle_bag()?);
+    }
+}
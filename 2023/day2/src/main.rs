@@ -1,90 +1,57 @@
-use std::fs;
-
-const INPUT_FILE: &str = "src/input.txt";
-
-#[derive(Debug)]
-struct CubeNums {
-    red: i32,
-    green: i32,
-    blue: i32,
-}
-
-#[derive(Debug)]
-struct Game {
-    num: i32,
-    rounds: Vec<CubeNums>,
-}
-
-fn parse_round(s: &str) -> CubeNums {
-    // Takes a string of the form "x red, y green, z blue"
-    // may be any subset in any order
-
-    let splits = s.split(", ");
-
-    let mut round = CubeNums {red: 0, green: 0, blue: 0};
-
-    for split in splits {
-        let dice_info: Vec<&str> = split.split(" ").collect();
-        let num = dice_info.get(0).unwrap().parse::<i32>().unwrap();
-
-        match *dice_info.get(1).unwrap() {
-            "red" => round.red = num,
-            "green" => round.green = num,
-            "blue" => round.blue = num,
-            &_ => panic!("unknown color"),
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day2::{CubeNums, Day2, Game};
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: day2 [--bag BAG_FILE] [INPUT]";
+
+fn solve(path: &str, bag_path: &str) -> Result<(i32, u64), String> {
+    let bag = CubeNums::load_bag(bag_path)?;
+    let contents = aoc_common::load_input(path)?;
+    let games: Vec<Game> = Day2::parse(&contents).map_err(|e| e.with_file(path).to_string())?;
+    return Ok((
+        day2::possible_total(&games, &bag),
+        day2::power_total(&games, &bag)?,
+    ));
+}
+
+/// Solves the puzzle, optionally with the bag read from a config file instead of the
+/// puzzle's own
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut bag = None;
+    let mut path = INPUT_FILE.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => match args.next() {
+                Some(file) => bag = Some(file),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => path = arg,
         }
     }
 
-    return round;
-}
-
-fn parse_game(s: &str) -> Game {
-
-    let colon_split: Vec<&str> = s.split(": ").collect();
-
-    let game_num = colon_split.get(0).unwrap().split(" ").nth(1).unwrap().parse::<i32>().unwrap();
-    let rounds: Vec<CubeNums> = colon_split.get(1).unwrap().split("; ").map(|s| parse_round(s)).collect();
-
-    return Game{
-        num: game_num,
-        rounds: rounds,
+    let Some(bag) = bag else {
+        return aoc_common::run::<Day2>(&path);
     };
-}
-
-fn game_possible(game: Game, cube_nums: &CubeNums) -> Option<i32> {
-
-    for round in game.rounds {
-
-        if round.red > cube_nums.red { return None };
-        if round.green > cube_nums.green { return None };
-        if round.blue > cube_nums.blue { return None };
-    }
-
-    return Some(game.num);
-}
-
-fn min_cube_counts(game: &Game) -> CubeNums {
-    return CubeNums {
-        red: game.rounds.iter().map(|r| r.red).max().unwrap(),
-        green: game.rounds.iter().map(|r| r.green).max().unwrap(),
-        blue: game.rounds.iter().map(|r| r.blue).max().unwrap()
+    match solve(&path, &bag) {
+        Ok((part1, part2)) => {
+            aoc_common::print_answer(1, part1);
+            aoc_common::print_answer(2, part2);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
     }
 }
-
-fn power(nums: Vec<i32>) -> i32 {
-    return nums.into_iter().reduce(|acc, e| acc * e).unwrap();
-}
-
-fn main() {
-    let contents = fs::read_to_string(INPUT_FILE).expect("Unable to read the file");
-    let games = contents.split("\n").map(|s| parse_game(s));
-
-    let cube_nums = CubeNums {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-
-    let result: i32 = games.map(|g| min_cube_counts(&g)).map(|c| power(Vec::from([c.red, c.green, c.blue]))).sum();
-    println!("{:#?}", result);
-}
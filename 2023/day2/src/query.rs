@@ -0,0 +1,504 @@
+//! A small language for asking questions about games, e.g.
+//! `max(red) > 10 && any_round(blue == 0)` or `rounds >= 5`.
+//!
+//! A query is a condition on one game. At that level there are the numbers `game` (the
+//! game's number) and `rounds`, and functions over its rounds:
+//!
+//! - `max(e)`, `min(e)` and `sum(e)` of a number `e` over the rounds
+//! - `any_round(c)`, `all_rounds(c)` and `count_rounds(c)` for a condition `c` on a round
+//!
+//! Inside those, any colour name is the number of cubes of that colour drawn in the round,
+//! and `total` is the number of cubes drawn. Numbers combine with `+`, `-` and `*`,
+//! compare with `==`, `!=`, `<`, `<=`, `>` and `>=`, and conditions combine with `!`, `&&`
+//! and `||`.
+
+use std::fmt;
+
+use aoc_common::ParseError;
+
+use crate::{CubeNums, Game};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Type {
+    Number,
+    Condition,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Number => write!(f, "a number"),
+            Type::Condition => write!(f, "a condition"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Op {
+    fn from_symbol(symbol: &str) -> Option<Op> {
+        let op = match symbol {
+            "||" => Op::Or,
+            "&&" => Op::And,
+            "==" => Op::Eq,
+            "!=" => Op::Ne,
+            "<" => Op::Lt,
+            "<=" => Op::Le,
+            ">" => Op::Gt,
+            ">=" => Op::Ge,
+            "+" => Op::Add,
+            "-" => Op::Sub,
+            "*" => Op::Mul,
+            _ => return None,
+        };
+        return Some(op);
+    }
+
+    /// Operators that bind tighter have higher precedence
+    fn precedence(&self) -> u8 {
+        match self {
+            Op::Or => return 1,
+            Op::And => return 2,
+            Op::Eq | Op::Ne | Op::Lt | Op::Le | Op::Gt | Op::Ge => return 3,
+            Op::Add | Op::Sub => return 4,
+            Op::Mul => return 5,
+        }
+    }
+
+    /// The types of the operands and of the result
+    fn types(&self) -> (Type, Type) {
+        match self {
+            Op::Or | Op::And => return (Type::Condition, Type::Condition),
+            Op::Add | Op::Sub | Op::Mul => return (Type::Number, Type::Number),
+            _ => return (Type::Number, Type::Condition),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Fold {
+    Max,
+    Min,
+    Sum,
+    Any,
+    All,
+    Count,
+}
+
+impl Fold {
+    fn from_name(name: &str) -> Option<Fold> {
+        let fold = match name {
+            "max" => Fold::Max,
+            "min" => Fold::Min,
+            "sum" => Fold::Sum,
+            "any_round" => Fold::Any,
+            "all_rounds" => Fold::All,
+            "count_rounds" => Fold::Count,
+            _ => return None,
+        };
+        return Some(fold);
+    }
+
+    /// The type of the per-round argument and of the result
+    fn types(&self) -> (Type, Type) {
+        match self {
+            Fold::Max | Fold::Min | Fold::Sum => return (Type::Number, Type::Number),
+            Fold::Any | Fold::All => return (Type::Condition, Type::Condition),
+            Fold::Count => return (Type::Condition, Type::Number),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Number(i64),
+    GameNum,
+    Rounds,
+    /// Cubes of a colour drawn in the current round
+    Colour(String),
+    /// Cubes drawn in the current round
+    Total,
+    Not(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Fold(Fold, Box<Expr>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Value {
+    Number(i64),
+    Bool(bool),
+}
+
+impl Value {
+    fn number(self) -> i64 {
+        match self {
+            Value::Number(n) => return n,
+            Value::Bool(_) => unreachable!("queries are type checked"),
+        }
+    }
+
+    fn bool(self) -> bool {
+        match self {
+            Value::Bool(b) => return b,
+            Value::Number(_) => unreachable!("queries are type checked"),
+        }
+    }
+}
+
+impl Expr {
+    fn eval(&self, game: &Game, round: Option<&CubeNums>) -> Value {
+        let round_value = || round.expect("round values are only parsed inside a round");
+        match self {
+            Expr::Number(n) => return Value::Number(*n),
+            Expr::GameNum => return Value::Number(game.num as i64),
+            Expr::Rounds => return Value::Number(game.rounds.len() as i64),
+            Expr::Colour(colour) => return Value::Number(round_value().get(colour) as i64),
+            Expr::Total => {
+                let total: i32 = round_value().iter().map(|(_, n)| n).sum();
+                return Value::Number(total as i64);
+            }
+            Expr::Not(e) => return Value::Bool(!e.eval(game, round).bool()),
+            Expr::Binary(op, l, r) => {
+                let l = l.eval(game, round);
+                match op {
+                    // Short-circuit, like Rust
+                    Op::Or => return Value::Bool(l.bool() || r.eval(game, round).bool()),
+                    Op::And => return Value::Bool(l.bool() && r.eval(game, round).bool()),
+                    _ => {}
+                }
+
+                let (l, r) = (l.number(), r.eval(game, round).number());
+                let value = match op {
+                    Op::Eq => Value::Bool(l == r),
+                    Op::Ne => Value::Bool(l != r),
+                    Op::Lt => Value::Bool(l < r),
+                    Op::Le => Value::Bool(l <= r),
+                    Op::Gt => Value::Bool(l > r),
+                    Op::Ge => Value::Bool(l >= r),
+                    Op::Add => Value::Number(l.saturating_add(r)),
+                    Op::Sub => Value::Number(l.saturating_sub(r)),
+                    Op::Mul => Value::Number(l.saturating_mul(r)),
+                    Op::Or | Op::And => unreachable!(),
+                };
+                return value;
+            }
+            Expr::Fold(fold, e) => {
+                let mut values = game.rounds.iter().map(|r| e.eval(game, Some(r)));
+                let value = match fold {
+                    Fold::Max => Value::Number(values.map(Value::number).max().unwrap_or(0)),
+                    Fold::Min => Value::Number(values.map(Value::number).min().unwrap_or(0)),
+                    Fold::Sum => Value::Number(values.map(Value::number).sum()),
+                    Fold::Any => Value::Bool(values.any(Value::bool)),
+                    Fold::All => Value::Bool(values.all(Value::bool)),
+                    Fold::Count => Value::Number(values.filter(|v| v.bool()).count() as i64),
+                };
+                return value;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Number(&'a str),
+    Name(&'a str),
+    Op(&'a str),
+    Open,
+    Close,
+    End,
+}
+
+/// Tokens with their byte offsets in the query
+fn tokenize(text: &str) -> Result<Vec<(Token<'_>, usize)>, ParseError> {
+    const OPERATORS: [&str; 12] = [
+        "||", "&&", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "!",
+    ];
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    while let Some(c) = text[start..].chars().next() {
+        let rest = &text[start..];
+        let len = if c.is_whitespace() {
+            start += c.len_utf8();
+            continue;
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            tokens.push((Token::Number(&rest[..len]), start));
+            len
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push((Token::Name(&rest[..len]), start));
+            len
+        } else if c == '(' || c == ')' {
+            tokens.push((if c == '(' { Token::Open } else { Token::Close }, start));
+            1
+        } else {
+            // Longest match first, so `<=` isn't read as `<` then `=`
+            let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(*op)) else {
+                let token = &rest[..c.len_utf8()];
+                return Err(error(text, start, token, format!("Unexpected \"{}\"", c)));
+            };
+            tokens.push((Token::Op(&rest[..op.len()]), start));
+            op.len()
+        };
+        start += len;
+    }
+
+    tokens.push((Token::End, text.len()));
+    return Ok(tokens);
+}
+
+fn error(text: &str, offset: usize, token: &str, message: impl Into<String>) -> ParseError {
+    let column = text[..offset].chars().count();
+    return ParseError::new(text, 0, column, token, message);
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    tokens: Vec<(Token<'a>, usize)>,
+    next: usize,
+    /// Whether names refer to a single round, i.e. we're inside a function's argument
+    in_round: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> &(Token<'a>, usize) {
+        return &self.tokens[self.next];
+    }
+
+    fn advance(&mut self) -> (Token<'a>, usize) {
+        let token = self.tokens[self.next].clone();
+        if token.0 != Token::End {
+            self.next += 1;
+        }
+        return token;
+    }
+
+    fn token_text(&self, token: &(Token<'a>, usize)) -> &'a str {
+        match token.0 {
+            Token::Number(s) | Token::Name(s) | Token::Op(s) => return s,
+            Token::Open => return "(",
+            Token::Close => return ")",
+            Token::End => return "",
+        }
+    }
+
+    fn error_at(&self, token: &(Token<'a>, usize), message: impl Into<String>) -> ParseError {
+        return error(self.text, token.1, self.token_text(token), message);
+    }
+
+    /// Parses an expression of the expected type, reporting a mismatch at its start
+    fn expect(&mut self, min_precedence: u8, expected: Type) -> Result<Expr, ParseError> {
+        let start = self.peek().clone();
+        let (expr, found) = self.expression(min_precedence)?;
+        if found != expected {
+            return Err(self.error_at(&start, format!("Expected {}, found {}", expected, found)));
+        }
+        return Ok(expr);
+    }
+
+    /// Precedence climbing over the binary operators
+    fn expression(&mut self, min_precedence: u8) -> Result<(Expr, Type), ParseError> {
+        let start = self.peek().clone();
+        let (mut expr, mut ty) = self.unary()?;
+
+        loop {
+            let token = self.peek().clone();
+            let op = match token.0 {
+                Token::Op(symbol) => match Op::from_symbol(symbol) {
+                    Some(op) if op.precedence() >= min_precedence => op,
+                    _ => break,
+                },
+                _ => break,
+            };
+            self.advance();
+
+            let (operand, result) = op.types();
+            if ty != operand {
+                return Err(self.error_at(
+                    &start,
+                    format!(
+                        "\"{}\" needs {}, found {}",
+                        self.token_text(&token),
+                        operand,
+                        ty
+                    ),
+                ));
+            }
+            let rhs = self.expect(op.precedence() + 1, operand)?;
+
+            // Comparisons don't chain: `a < b < c` is an error rather than a surprise
+            if op.precedence() == 3 {
+                if let (Token::Op(next), _) = self.peek() {
+                    if Op::from_symbol(next).is_some_and(|next| next.precedence() == 3) {
+                        let next = self.peek().clone();
+                        return Err(self.error_at(&next, "Comparisons can't be chained"));
+                    }
+                }
+            }
+
+            expr = Expr::Binary(op, Box::new(expr), Box::new(rhs));
+            ty = result;
+        }
+
+        return Ok((expr, ty));
+    }
+
+    fn unary(&mut self) -> Result<(Expr, Type), ParseError> {
+        let token = self.advance();
+        match token.0 {
+            Token::Number(n) => {
+                let n = n.parse().map_err(|e| {
+                    self.error_at(&token, format!("Could not parse \"{}\": {}", n, e))
+                })?;
+                return Ok((Expr::Number(n), Type::Number));
+            }
+            Token::Op("!") => {
+                let e = self.expect(u8::MAX, Type::Condition)?;
+                return Ok((Expr::Not(Box::new(e)), Type::Condition));
+            }
+            Token::Open => {
+                let inner = self.expression(0)?;
+                self.close()?;
+                return Ok(inner);
+            }
+            Token::Name(name) => return self.name(&token, name),
+            _ => {
+                let found = match token.0 {
+                    Token::End => "the end of the query".to_string(),
+                    _ => format!("\"{}\"", self.token_text(&token)),
+                };
+                return Err(self.error_at(&token, format!("Expected a value, found {}", found)));
+            }
+        }
+    }
+
+    fn close(&mut self) -> Result<(), ParseError> {
+        let token = self.advance();
+        if token.0 != Token::Close {
+            return Err(self.error_at(&token, "Expected \")\""));
+        }
+        return Ok(());
+    }
+
+    fn name(
+        &mut self,
+        token: &(Token<'a>, usize),
+        name: &'a str,
+    ) -> Result<(Expr, Type), ParseError> {
+        if let Some(fold) = Fold::from_name(name) {
+            if self.in_round {
+                return Err(
+                    self.error_at(token, format!("{}() can't be used inside a round", name))
+                );
+            }
+            if self.peek().0 != Token::Open {
+                let next = self.peek().clone();
+                return Err(self.error_at(&next, format!("Expected \"(\" after {}", name)));
+            }
+            self.advance();
+
+            let (argument, result) = fold.types();
+            self.in_round = true;
+            let e = self.expect(0, argument);
+            self.in_round = false;
+            let e = e?;
+            self.close()?;
+            return Ok((Expr::Fold(fold, Box::new(e)), result));
+        }
+
+        let expr = match (name, self.in_round) {
+            ("game", false) => Expr::GameNum,
+            ("rounds", false) => Expr::Rounds,
+            ("total", true) => Expr::Total,
+            ("game" | "rounds", true) => {
+                return Err(
+                    self.error_at(token, format!("\"{}\" can't be used inside a round", name))
+                )
+            }
+            (_, true) => Expr::Colour(name.to_string()),
+            (_, false) => {
+                return Err(self.error_at(
+                    token,
+                    format!(
+                        "\"{}\" is only known inside a round, e.g. max({}) or any_round({} > 0)",
+                        name, name, name
+                    ),
+                ))
+            }
+        };
+        return Ok((expr, Type::Number));
+    }
+}
+
+/// A parsed condition on a game
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    expr: Expr,
+}
+
+/// The games a query matched
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Matches {
+    /// Numbers of the matching games, in input order
+    pub games: Vec<i32>,
+    /// Number of games the query was run against
+    pub of: usize,
+    /// Sum of the matching games' numbers
+    pub sum: i64,
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Query, ParseError> {
+        let mut parser = Parser {
+            text,
+            tokens: tokenize(text)?,
+            next: 0,
+            in_round: false,
+        };
+
+        let expr = parser.expect(0, Type::Condition)?;
+        let token = parser.peek().clone();
+        if token.0 != Token::End {
+            return Err(parser.error_at(
+                &token,
+                format!("Unexpected \"{}\"", parser.token_text(&token)),
+            ));
+        }
+        return Ok(Query { expr });
+    }
+
+    pub fn matches(&self, game: &Game) -> bool {
+        return self.expr.eval(game, None).bool();
+    }
+
+    pub fn run(&self, games: &[Game]) -> Matches {
+        let games_matched: Vec<i32> = games
+            .iter()
+            .filter(|game| self.matches(game))
+            .map(|game| game.num)
+            .collect();
+        return Matches {
+            sum: games_matched.iter().map(|&n| n as i64).sum(),
+            of: games.len(),
+            games: games_matched,
+        };
+    }
+}
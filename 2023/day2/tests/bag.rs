@@ -0,0 +1,79 @@
+use aoc_common::Solution;
+use day2::{game_possible, min_cube_counts, power_total, CubeNums, Day2};
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[test]
+fn puzzle_bag_solves_the_example() {
+    let answers = aoc_common::solve::<Day2>(EXAMPLE, &[1, 2]).unwrap();
+    assert_eq!(answers, vec!["8", "2286"]);
+}
+
+#[test]
+fn any_colour_can_be_drawn() {
+    let games = Day2::parse("Game 7: 2 teal, 1 red; 3 mauve\nGame 8: 1 red").unwrap();
+    assert_eq!(games[0].rounds[0].get("teal"), 2);
+    assert_eq!(games[0].rounds[1].get("red"), 0);
+
+    let bag = CubeNums::parse_bag("red = 1\nteal = 2\n").unwrap();
+    assert_eq!(game_possible(&games[0], &bag), None);
+    assert_eq!(game_possible(&games[1], &bag), Some(8));
+
+    let min = min_cube_counts(&games[0], &bag);
+    let colours: Vec<(&str, i32)> = min.iter().collect();
+    assert_eq!(colours, vec![("mauve", 3), ("red", 1), ("teal", 2)]);
+    assert_eq!(min.power(), Some(6));
+    // A bag colour the game never drew makes its power 0
+    assert_eq!(min_cube_counts(&games[1], &bag).power(), Some(0));
+}
+
+#[test]
+fn powers_that_overflow_are_errors() {
+    let bag = CubeNums::parse_bag("red = 1").unwrap();
+    let games = Day2::parse("Game 1: 2000000000 red, 2000000000 blue, 2000000000 green").unwrap();
+    assert_eq!(min_cube_counts(&games[0], &bag).power(), None);
+    assert!(power_total(&games, &bag).is_err());
+
+    // Each power fits in u64, but not their sum
+    let games = Day2::parse(
+        "Game 1: 2000000000 red, 2000000000 blue, 4 green
+Game 2: 2000000000 red, 2000000000 blue, 2 green",
+    )
+    .unwrap();
+    assert_eq!(
+        min_cube_counts(&games[0], &bag).power(),
+        Some(16 * 10u64.pow(18))
+    );
+    let error = power_total(&games, &bag).unwrap_err();
+    assert!(error.contains("game 2"), "{}", error);
+}
+
+#[test]
+fn bag_files_report_bad_lines() {
+    let bag = CubeNums::parse_bag("# comment\n\nred = 3\n  blue=4  \n").unwrap();
+    assert_eq!(
+        (bag.get("red"), bag.get("blue"), bag.get("green")),
+        (3, 4, 0)
+    );
+
+    let err = CubeNums::parse_bag("red = 3\nblue 4\n").unwrap_err();
+    assert!(err.to_string().contains("Expected \"=\""), "{}", err);
+    let err = CubeNums::parse_bag("red = 3\nred = 4\n").unwrap_err();
+    assert!(err.to_string().contains("listed twice"), "{}", err);
+    assert!(CubeNums::parse_bag("red = lots").is_err());
+    assert!(CubeNums::parse_bag("# nothing\n").is_err());
+
+    assert!(Day2::parse("Game 1: 3 blue, 4 red, 1 blue").is_err());
+}
+
+#[test]
+fn negative_counts_are_errors() {
+    let err = Day2::parse("Game 1: -3 red").unwrap_err();
+    assert!(err.to_string().contains("can't be negative"), "{}", err);
+    assert!(CubeNums::parse_bag("red = -1").is_err());
+}
@@ -0,0 +1,121 @@
+use aoc_common::Solution;
+use day2::estimate::{estimate_bag, Bounds, Options, MAX_PER_COLOUR_LIMIT};
+use day2::{CubeNums, Day2, Game};
+
+fn bag(text: &str) -> CubeNums {
+    return CubeNums::parse_bag(text).unwrap();
+}
+
+#[test]
+fn a_single_mixed_round_is_most_likely_from_the_smallest_bag() {
+    let games = Day2::parse("Game 1: 1 red, 1 blue").unwrap();
+    let estimate =
+        estimate_bag(&games[0], &bag("red = 12\nblue = 14"), &Options::default()).unwrap();
+
+    assert_eq!(estimate.likely, estimate.min);
+    assert_eq!(estimate.log_likelihood, 0.0);
+}
+
+#[test]
+fn repeated_colours_make_a_larger_bag_likelier() {
+    // One cube per round, red twice as often as blue: a 2:1 bag beats the smallest 1:1 one
+    let games = Day2::parse("Game 1: 1 red; 1 red; 1 blue").unwrap();
+    let options = Options {
+        max_per_colour: 20,
+        level: 0.5,
+    };
+    let estimate = estimate_bag(&games[0], &bag("red = 1\nblue = 1"), &options).unwrap();
+
+    assert_eq!(estimate.min, bag("red = 1\nblue = 1"));
+    assert_eq!(estimate.likely, bag("red = 2\nblue = 1"));
+    assert!((estimate.log_likelihood - (4.0f64 / 27.0).ln()).abs() < 1e-9);
+
+    let red = estimate.bounds["red"];
+    assert!(red.low >= 1 && red.low <= 2 && red.high >= 2, "{:?}", red);
+    // Three draws say little about the bag's size
+    assert_eq!(
+        estimate.bounds["blue"],
+        Bounds {
+            low: 1,
+            high: 20,
+            capped: true
+        }
+    );
+}
+
+#[test]
+fn likely_bags_are_never_below_the_smallest() {
+    let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+    let games = Day2::parse(input).unwrap();
+    let bag = CubeNums::parse_bag(day2::BAG).unwrap();
+
+    for game in &games {
+        let estimate = estimate_bag(game, &bag, &Options::default()).unwrap();
+        for (colour, count) in estimate.likely.iter() {
+            let bounds = estimate.bounds[colour];
+            assert!(
+                estimate.min.get(colour) <= bounds.low,
+                "{} {:?}",
+                colour,
+                bounds
+            );
+            assert!(
+                bounds.low <= count && count <= bounds.high,
+                "{} {:?}",
+                colour,
+                bounds
+            );
+        }
+    }
+
+    let options = Options {
+        max_per_colour: 10,
+        ..Options::default()
+    };
+    assert!(estimate_bag(&games[2], &bag, &options).is_err());
+    let options = Options {
+        level: 1.0,
+        ..Options::default()
+    };
+    assert!(estimate_bag(&games[0], &bag, &options).is_err());
+}
+
+#[test]
+fn huge_limits_and_negative_counts_are_errors() {
+    let games = Day2::parse("Game 1: 1 red, 1 blue").unwrap();
+    let options = Options {
+        max_per_colour: MAX_PER_COLOUR_LIMIT + 1,
+        ..Options::default()
+    };
+    assert!(estimate_bag(&games[0], &bag("red = 12"), &options).is_err());
+
+    let mut round = CubeNums::new();
+    round.set("red", -3);
+    let game = Game {
+        num: 1,
+        rounds: vec![round],
+    };
+    assert!(estimate_bag(&game, &bag("red = 12"), &Options::default()).is_err());
+}
+
+#[test]
+fn counts_that_reach_the_limit_are_flagged() {
+    // Drawing two of a colour is likelier the more of it there is
+    let games = Day2::parse("Game 1: 2 red; 2 blue\nGame 2: 2 red; 2 red; 1 blue").unwrap();
+    let options = Options {
+        max_per_colour: 20,
+        level: 0.95,
+    };
+    let bag = bag("red = 1\nblue = 1");
+
+    let estimate = estimate_bag(&games[0], &bag, &options).unwrap();
+    assert_eq!(estimate.likely, self::bag("red = 20\nblue = 20"));
+    assert_eq!(estimate.capped, vec!["blue", "red"]);
+
+    let estimate = estimate_bag(&games[1], &bag, &options).unwrap();
+    assert_eq!(estimate.likely, self::bag("red = 5\nblue = 1"));
+    assert!(estimate.capped.is_empty());
+}
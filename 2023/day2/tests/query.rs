@@ -0,0 +1,89 @@
+use aoc_common::Solution;
+use day2::query::{Matches, Query};
+use day2::{Day2, Game};
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+fn games() -> Vec<Game> {
+    return Day2::parse(EXAMPLE).unwrap();
+}
+
+fn matching(query: &str) -> Vec<i32> {
+    return Query::parse(query).unwrap().run(&games()).games;
+}
+
+#[test]
+fn queries_select_games() {
+    assert_eq!(
+        matching("max(red) > 10 && any_round(blue == 0)"),
+        vec![3, 4]
+    );
+    assert_eq!(matching("rounds >= 3"), vec![1, 2, 3, 4]);
+    assert_eq!(matching("rounds == 2 || game == 1"), vec![1, 5]);
+    assert_eq!(matching("!any_round(total > 10)"), vec![1, 2, 5]);
+    assert_eq!(matching("all_rounds(red > 0)"), vec![3, 4, 5]);
+    assert_eq!(matching("count_rounds(green > 0) == 2"), vec![1, 5]);
+    assert_eq!(matching("sum(red + blue) - min(green) * 2 >= 40"), vec![4]);
+    assert_eq!(matching("any_round(purple > 0)"), Vec::<i32>::new());
+}
+
+#[test]
+fn part_one_as_a_query() {
+    let query = "all_rounds(red <= 12 && green <= 13 && blue <= 14)";
+    let matches = Query::parse(query).unwrap().run(&games());
+    assert_eq!(
+        matches,
+        Matches {
+            games: vec![1, 2, 5],
+            of: 5,
+            sum: 8
+        }
+    );
+}
+
+#[test]
+fn mistakes_are_located() {
+    let error = |query: &str| {
+        let e = Query::parse(query).unwrap_err();
+        return (e.column, e.message);
+    };
+
+    assert_eq!(
+        error("max(red) > 10 &&"),
+        (
+            17,
+            "Expected a value, found the end of the query".to_string()
+        )
+    );
+    assert_eq!(
+        error("red > 1"),
+        (
+            1,
+            "\"red\" is only known inside a round, e.g. max(red) or any_round(red > 0)".to_string()
+        )
+    );
+    assert_eq!(
+        error("rounds"),
+        (1, "Expected a condition, found a number".to_string())
+    );
+    assert_eq!(
+        error("rounds && game > 1"),
+        (1, "\"&&\" needs a condition, found a number".to_string())
+    );
+    assert_eq!(
+        error("max(any_round(red > 1))"),
+        (5, "any_round() can't be used inside a round".to_string())
+    );
+    assert_eq!(
+        error("1 < rounds < 3"),
+        (12, "Comparisons can't be chained".to_string())
+    );
+    assert_eq!(error("rounds > 1 )"), (12, "Unexpected \")\"".to_string()));
+    assert_eq!(error("max(red + 1"), (12, "Expected \")\"".to_string()));
+    assert_eq!(error("rounds = 1"), (8, "Unexpected \"=\"".to_string()));
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::process::ExitCode;
+
+use day3::render::Annotation;
+use day3::{Rules, Schematic};
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: render [--html OUTPUT] [INPUT]";
+
+/// Prints the schematic with counted and uncounted part numbers and gears highlighted,
+/// or writes it as an HTML page with `--html`
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut html = None;
+    let mut path = INPUT_FILE.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--html" => match args.next() {
+                Some(output) => html = Some(output),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => path = arg,
+        }
+    }
+
+    let schematic = match aoc_common::load_input(&path).and_then(|contents| {
+        Schematic::parse(&contents).map_err(|e| e.with_file(&path).to_string())
+    }) {
+        Ok(schematic) => schematic,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let annotation = match Annotation::new(&schematic, &Rules::default()) {
+        Ok(annotation) => annotation,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match html {
+        None => print!("{}", annotation.to_ansi()),
+        Some(output) => {
+            if let Err(e) = fs::write(&output, annotation.to_html()) {
+                eprintln!("Error: Unable to write {}: {}", output, e);
+                return ExitCode::FAILURE;
+            }
+            println!("Wrote {}", output);
+        }
+    }
+    return ExitCode::SUCCESS;
+}
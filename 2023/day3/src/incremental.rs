@@ -0,0 +1,230 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_common::grid::Position;
+
+use crate::rules::Rules;
+use crate::schematic::{PartId, Schematic};
+
+/// Change to the part 1 and part 2 totals caused by one edit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Delta {
+    pub part1: i64,
+    pub part2: i64,
+}
+
+/// A schematic that keeps its part 1 and part 2 totals up to date as single cells are
+/// edited, only revisiting the numbers and symbols around each edit
+pub struct LiveSchematic {
+    schematic: Schematic,
+    rules: Rules,
+    /// The part numbers near each symbol, and its gear ratio if it is a gear
+    near: HashMap<Position, (Vec<PartId>, u64)>,
+    /// How many counted symbols are near each part number, by id
+    support: Vec<usize>,
+    part1: u64,
+    part2: u64,
+}
+
+impl LiveSchematic {
+    /// Fails if a gear ratio or their sum overflows u64
+    pub fn new(schematic: Schematic, rules: Rules) -> Result<LiveSchematic, String> {
+        let mut live = LiveSchematic {
+            schematic,
+            rules,
+            near: HashMap::new(),
+            support: Vec::new(),
+            part1: 0,
+            part2: 0,
+        };
+
+        let symbols: BTreeSet<(Position, char)> = live.schematic.symbols().collect();
+        live.part2 = live.add_symbols(&symbols)?;
+        let ids: Vec<PartId> = live.schematic.parts().map(|(id, _)| id).collect();
+        live.part1 = live.counted_total(&ids);
+        return Ok(live);
+    }
+
+    pub fn schematic(&self) -> &Schematic {
+        return &self.schematic;
+    }
+
+    /// Sum of every part number
+    pub fn part1(&self) -> u64 {
+        return self.part1;
+    }
+
+    /// Sum of every gear ratio
+    pub fn part2(&self) -> u64 {
+        return self.part2;
+    }
+
+    fn support_of(&self, id: PartId) -> usize {
+        return self.support.get(id.0).copied().unwrap_or(0);
+    }
+
+    /// Sum of the numbers among `ids` that are next to a counted symbol
+    fn counted_total(&self, ids: &[PartId]) -> u64 {
+        return ids
+            .iter()
+            .filter(|&&id| self.support_of(id) > 0)
+            .map(|&id| self.schematic.part(id).value as u64)
+            .sum();
+    }
+
+    /// Indexes the symbol at `pos`, returning its gear ratio if it is a gear. If the ratio
+    /// overflows, the index is left as it was.
+    fn add_symbol(&mut self, pos: Position, symbol: char) -> Result<u64, String> {
+        let parts = self.rules.parts_near(&self.schematic, pos);
+        let gear = self
+            .rules
+            .gear(&self.schematic, pos, symbol, parts.clone())?;
+        let ratio = gear.map_or(0, |g| g.ratio);
+        if self.rules.part_symbols.matches(symbol) {
+            for id in &parts {
+                if self.support.len() <= id.0 {
+                    self.support.resize(id.0 + 1, 0);
+                }
+                self.support[id.0] += 1;
+            }
+        }
+
+        self.near.insert(pos, (parts, ratio));
+        return Ok(ratio);
+    }
+
+    /// Indexes every one of `symbols`, returning the sum of their gear ratios. If that
+    /// overflows, the index is left as it was.
+    fn add_symbols(&mut self, symbols: &BTreeSet<(Position, char)>) -> Result<u64, String> {
+        let mut total: u64 = 0;
+        let mut added = Vec::new();
+        for &(pos, symbol) in symbols {
+            let sum = self.add_symbol(pos, symbol).and_then(|ratio| {
+                added.push((pos, symbol));
+                return total
+                    .checked_add(ratio)
+                    .ok_or("Sum of the gear ratios overflows u64".to_string());
+            });
+            match sum {
+                Ok(sum) => total = sum,
+                Err(e) => {
+                    for &(pos, symbol) in &added {
+                        self.remove_symbol(pos, symbol);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        return Ok(total);
+    }
+
+    /// Drops the symbol at `pos` from the index, returning its gear ratio if it was a gear
+    fn remove_symbol(&mut self, pos: Position, symbol: char) -> u64 {
+        let (parts, ratio) = self.near.remove(&pos).unwrap_or_default();
+        if self.rules.part_symbols.matches(symbol) {
+            for id in &parts {
+                self.support[id.0] -= 1;
+            }
+        }
+        return ratio;
+    }
+
+    /// Part numbers whose value or status an edit at `pos` can change: the ones next to it
+    /// in its row, and the ones a symbol there would reach
+    fn parts_affected_by(&self, pos: Position) -> Vec<PartId> {
+        let mut ids: BTreeSet<PartId> = self
+            .rules
+            .parts_near(&self.schematic, pos)
+            .into_iter()
+            .collect();
+        for col in [pos.1.checked_sub(1), Some(pos.1), Some(pos.1 + 1)]
+            .into_iter()
+            .flatten()
+        {
+            ids.extend(self.schematic.part_at((pos.0, col)));
+        }
+        return ids.into_iter().collect();
+    }
+
+    /// Symbols whose neighbourhood includes `pos` or any digit of `ids`
+    fn symbols_near(&self, pos: Position, ids: &[PartId]) -> BTreeSet<(Position, char)> {
+        let mut cells = vec![pos];
+        for &id in ids {
+            let span = self.schematic.part(id).span;
+            cells.extend((span.col..span.col + span.len).map(|col| (span.row, col)));
+        }
+
+        let offsets = self.rules.neighbourhood.offsets();
+        let mut symbols = BTreeSet::new();
+        symbols.extend(self.schematic.symbol_at(pos).map(|s| (pos, s)));
+        for (row, col) in cells {
+            for d in &offsets {
+                // The neighbourhood is symmetric, so a symbol reaches this cell exactly when
+                // this cell reaches the symbol
+                let Some(r) = row.checked_add_signed(d.0) else {
+                    continue;
+                };
+                let Some(c) = col.checked_add_signed(d.1) else {
+                    continue;
+                };
+                if let Some(symbol) = self.schematic.symbol_at((r, c)) {
+                    symbols.insert(((r, c), symbol));
+                }
+            }
+        }
+        return symbols;
+    }
+
+    /// Writes `c` at `pos` and updates the totals, returning how much they changed. If a
+    /// gear ratio would overflow, the edit is undone.
+    pub fn apply_edit(&mut self, pos: Position, c: char) -> Result<Delta, String> {
+        let old_c = self.schematic.char_at(pos);
+        // New numbers only cover cells of the old ones and `pos`, so every symbol whose
+        // neighbours can change is found before the edit
+        let parts_before = self.parts_affected_by(pos);
+        let symbols_before = self.symbols_near(pos, &parts_before);
+
+        // Take out everything the edit can change...
+        let old_part1 = self.counted_total(&parts_before);
+        let mut old_part2 = 0;
+        for &(symbol_pos, symbol) in &symbols_before {
+            old_part2 += self.remove_symbol(symbol_pos, symbol);
+        }
+
+        if let Err(e) = self.schematic.set_char(pos, c) {
+            // Nothing changed, so put the symbols back as they were
+            self.add_symbols(&symbols_before)
+                .expect("the ratios fitted before the edit");
+            return Err(e);
+        }
+
+        // ...and put it back as it is now
+        let parts_after = self.parts_affected_by(pos);
+        let mut symbols_after: BTreeSet<(Position, char)> = symbols_before
+            .iter()
+            .copied()
+            .filter(|&(symbol_pos, _)| symbol_pos != pos)
+            .collect();
+        symbols_after.extend(self.schematic.symbol_at(pos).map(|s| (pos, s)));
+
+        let new_part2 = match self.add_symbols(&symbols_after) {
+            Ok(total) => total,
+            Err(e) => {
+                // Put the old character back, and its symbols with it
+                let old_c = old_c.expect("set_char accepted the position");
+                self.schematic.set_char(pos, old_c)?;
+                self.add_symbols(&symbols_before)
+                    .expect("the ratios fitted before the edit");
+                return Err(e);
+            }
+        };
+        let new_part1 = self.counted_total(&parts_after);
+
+        let delta = Delta {
+            part1: new_part1 as i64 - old_part1 as i64,
+            part2: new_part2 as i64 - old_part2 as i64,
+        };
+        self.part1 = (self.part1 as i64 + delta.part1) as u64;
+        self.part2 = (self.part2 as i64 + delta.part2) as u64;
+        return Ok(delta);
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+use aoc_common::{ParseError, Solution};
+
+pub mod incremental;
+pub mod render;
+pub mod rules;
+pub mod schematic;
+
+pub use incremental::{Delta, LiveSchematic};
+pub use rules::{Aggregation, Count, Gear, Neighbourhood, Rules, Symbols};
+pub use schematic::{PartId, PartNumber, Schematic, Span};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Schematic::parse(input);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(Rules::default().part_total(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return Rules::default().gear_total(input);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use aoc_common::grid::Position;
+
+use crate::rules::{Gear, Rules};
+use crate::schematic::{PartId, Schematic};
+
+/// How a cell is highlighted
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Style {
+    Plain,
+    Symbol,
+    Gear,
+    /// A part number next to a counted symbol
+    Counted,
+    /// A number next to no counted symbol
+    Uncounted,
+    /// A part number that is one of a gear's parts
+    GearPart,
+}
+
+/// Which numbers count and which symbols are gears under a set of rules
+pub struct Annotation<'a> {
+    schematic: &'a Schematic,
+    counted: HashSet<PartId>,
+    gear_parts: HashSet<PartId>,
+    pub gears: Vec<Gear>,
+}
+
+impl<'a> Annotation<'a> {
+    pub fn new(schematic: &'a Schematic, rules: &Rules) -> Result<Annotation<'a>, String> {
+        let gears = rules.gears(schematic)?;
+        return Ok(Annotation {
+            schematic,
+            counted: rules.part_numbers(schematic).into_iter().collect(),
+            gear_parts: gears.iter().flat_map(|g| g.parts.clone()).collect(),
+            gears,
+        });
+    }
+
+    pub fn style(&self, pos: Position) -> Style {
+        if let Some(id) = self.schematic.part_at(pos) {
+            if self.gear_parts.contains(&id) {
+                return Style::GearPart;
+            }
+            if self.counted.contains(&id) {
+                return Style::Counted;
+            }
+            return Style::Uncounted;
+        }
+        if self.schematic.symbol_at(pos).is_some() {
+            if self.gears.iter().any(|g| g.pos == pos) {
+                return Style::Gear;
+            }
+            return Style::Symbol;
+        }
+        return Style::Plain;
+    }
+
+    /// The schematic, with runs of equally styled cells wrapped by `paint`
+    fn render(&self, paint: impl Fn(Style, &str) -> String) -> String {
+        let mut out = String::new();
+        for row in 0..self.schematic.height() {
+            let mut run = String::new();
+            let mut run_style = Style::Plain;
+
+            for col in 0..self.schematic.width() {
+                let style = self.style((row, col));
+                if style != run_style && !run.is_empty() {
+                    out += &paint(run_style, &run);
+                    run.clear();
+                }
+                run_style = style;
+                run.push(self.schematic.char_at((row, col)).unwrap());
+            }
+            out += &paint(run_style, &run);
+            out += "\n";
+        }
+        return out;
+    }
+
+    /// One line per gear: its position, symbol, parts and ratio
+    pub fn gear_lines(&self) -> Vec<String> {
+        return self
+            .gears
+            .iter()
+            .map(|gear| {
+                let parts: Vec<String> = gear
+                    .parts
+                    .iter()
+                    .map(|&id| self.schematic.part(id).value.to_string())
+                    .collect();
+                format!(
+                    "{} at ({}, {}): {} -> {}",
+                    gear.symbol,
+                    gear.pos.0,
+                    gear.pos.1,
+                    parts.join(", "),
+                    gear.ratio
+                )
+            })
+            .collect();
+    }
+
+    /// The schematic for a terminal, followed by the list of gears
+    pub fn to_ansi(&self) -> String {
+        let mut out = self.render(|style, text| {
+            let code = match style {
+                Style::Plain => return text.to_string(),
+                Style::Symbol => "1",
+                Style::Gear => "1;33",
+                Style::Counted => "32",
+                Style::Uncounted => "31",
+                Style::GearPart => "1;36",
+            };
+            return format!("\x1b[{}m{}\x1b[0m", code, text);
+        });
+
+        out += "\n";
+        for line in self.gear_lines() {
+            out += &line;
+            out += "\n";
+        }
+        return out;
+    }
+
+    /// A standalone HTML page showing the schematic and the list of gears
+    pub fn to_html(&self) -> String {
+        let schematic = self.render(|style, text| {
+            let class = match style {
+                Style::Plain => return escape(text),
+                Style::Symbol => "symbol",
+                Style::Gear => "gear",
+                Style::Counted => "counted",
+                Style::Uncounted => "uncounted",
+                Style::GearPart => "gear-part",
+            };
+            return format!("<span class=\"{}\">{}</span>", class, escape(text));
+        });
+        let gears: String = self
+            .gear_lines()
+            .iter()
+            .map(|line| format!("<li>{}</li>\n", escape(line)))
+            .collect();
+
+        return format!(
+            "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Engine schematic</title>
+<style>
+body {{ background: #0f0f23; color: #cccccc; font-family: monospace; }}
+.symbol {{ color: #ffffff; font-weight: bold; }}
+.gear {{ color: #ffff66; font-weight: bold; }}
+.counted {{ color: #00cc00; }}
+.uncounted {{ color: #ff4444; }}
+.gear-part {{ color: #33cccc; font-weight: bold; }}
+</style>
+</head>
+<body>
+<p><span class=\"counted\">part number</span> <span class=\"uncounted\">not a part number</span> <span class=\"gear\">gear</span> <span class=\"gear-part\">gear part</span></p>
+<pre>
+{}</pre>
+<h2>Gears</h2>
+<ul>
+{}</ul>
+</body>
+</html>
+",
+            schematic, gears
+        );
+    }
+}
+
+fn escape(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+
+use aoc_common::grid::{Position, ORTHOGONAL, SURROUNDING};
+
+use crate::schematic::{PartId, Schematic};
+
+/// Which symbols a rule looks at
+#[derive(Clone, Debug, PartialEq)]
+pub enum Symbols {
+    Any,
+    Only(Vec<char>),
+}
+
+impl Symbols {
+    pub fn matches(&self, symbol: char) -> bool {
+        match self {
+            Symbols::Any => return true,
+            Symbols::Only(symbols) => return symbols.contains(&symbol),
+        }
+    }
+}
+
+/// The cells around a symbol that count as adjacent to it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Neighbourhood {
+    /// The four orthogonal cells
+    VonNeumann,
+    /// The eight surrounding cells
+    Moore,
+    /// Every cell within `k` steps in each direction, i.e. a (2k+1)x(2k+1) square
+    Radius(usize),
+}
+
+impl Neighbourhood {
+    /// (row, col) offsets of the cells in the neighbourhood
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        match self {
+            Neighbourhood::VonNeumann => return ORTHOGONAL.to_vec(),
+            Neighbourhood::Moore => return SURROUNDING.to_vec(),
+            Neighbourhood::Radius(k) => {
+                let k = *k as isize;
+                return (-k..=k)
+                    .flat_map(|dr| (-k..=k).map(move |dc| (dr, dc)))
+                    .filter(|&d| d != (0, 0))
+                    .collect();
+            }
+        }
+    }
+}
+
+/// How many adjacent part numbers a gear needs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    /// Inclusive at both ends
+    Between(usize, usize),
+}
+
+impl Count {
+    pub fn matches(&self, n: usize) -> bool {
+        match self {
+            Count::Exactly(count) => return n == *count,
+            Count::AtLeast(min) => return n >= *min,
+            Count::Between(min, max) => return n >= *min && n <= *max,
+        }
+    }
+}
+
+/// How a gear's part numbers combine into its ratio
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aggregation {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregation {
+    /// The combined value, or None if it overflows u64
+    pub fn apply(&self, mut values: impl Iterator<Item = u64>) -> Option<u64> {
+        match self {
+            Aggregation::Product => return values.try_fold(1u64, |acc, v| acc.checked_mul(v)),
+            Aggregation::Sum => return values.try_fold(0u64, |acc, v| acc.checked_add(v)),
+            Aggregation::Max => return Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+/// What makes a number a part number, and a symbol a gear
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rules {
+    /// Symbols that make the numbers next to them part numbers
+    pub part_symbols: Symbols,
+    /// Symbols that can be gears
+    pub gear_symbols: Symbols,
+    pub neighbourhood: Neighbourhood,
+    pub gear_parts: Count,
+    pub aggregation: Aggregation,
+}
+
+/// The puzzle's own rules: any symbol, the 8 surrounding cells, and gears being a `*`
+/// next to exactly two part numbers, whose product is the ratio
+impl Default for Rules {
+    fn default() -> Rules {
+        return Rules {
+            part_symbols: Symbols::Any,
+            gear_symbols: Symbols::Only(vec!['*']),
+            neighbourhood: Neighbourhood::Moore,
+            gear_parts: Count::Exactly(2),
+            aggregation: Aggregation::Product,
+        };
+    }
+}
+
+/// A symbol that satisfies the gear rule, with the part numbers next to it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gear {
+    pub pos: Position,
+    pub symbol: char,
+    pub parts: Vec<PartId>,
+    pub ratio: u64,
+}
+
+impl Rules {
+    /// Ids of the part numbers in the neighbourhood of `pos`, in id order
+    pub fn parts_near(&self, schematic: &Schematic, pos: Position) -> Vec<PartId> {
+        let ids: BTreeSet<PartId> = self
+            .neighbourhood
+            .offsets()
+            .into_iter()
+            .filter_map(|d| {
+                let row = pos.0.checked_add_signed(d.0)?;
+                let col = pos.1.checked_add_signed(d.1)?;
+                return schematic.part_at((row, col));
+            })
+            .collect();
+        return ids.into_iter().collect();
+    }
+
+    /// Ids of the numbers next to a counted symbol, in id order
+    pub fn part_numbers(&self, schematic: &Schematic) -> Vec<PartId> {
+        let ids: BTreeSet<PartId> = schematic
+            .symbols()
+            .filter(|(_, symbol)| self.part_symbols.matches(*symbol))
+            .flat_map(|(pos, _)| self.parts_near(schematic, pos))
+            .collect();
+        return ids.into_iter().collect();
+    }
+
+    /// The gear formed by `symbol` at `pos` and the part numbers `parts` near it, if any.
+    /// Fails if its ratio overflows u64.
+    pub fn gear(
+        &self,
+        schematic: &Schematic,
+        pos: Position,
+        symbol: char,
+        parts: Vec<PartId>,
+    ) -> Result<Option<Gear>, String> {
+        if !self.gear_symbols.matches(symbol) || !self.gear_parts.matches(parts.len()) {
+            return Ok(None);
+        }
+
+        let values = parts.iter().map(|&id| schematic.part(id).value as u64);
+        let Some(ratio) = self.aggregation.apply(values) else {
+            let ids: Vec<String> = parts.iter().map(|id| id.0.to_string()).collect();
+            return Err(format!(
+                "Ratio of the gear at {:?} with parts {} overflows u64",
+                pos,
+                ids.join(", ")
+            ));
+        };
+        return Ok(Some(Gear {
+            pos,
+            symbol,
+            parts,
+            ratio,
+        }));
+    }
+
+    pub fn gears(&self, schematic: &Schematic) -> Result<Vec<Gear>, String> {
+        let mut gears = Vec::new();
+        for (pos, symbol) in schematic.symbols() {
+            if !self.gear_symbols.matches(symbol) {
+                continue;
+            }
+            let parts = self.parts_near(schematic, pos);
+            gears.extend(self.gear(schematic, pos, symbol, parts)?);
+        }
+        return Ok(gears);
+    }
+
+    /// Sum of every part number (part 1)
+    pub fn part_total(&self, schematic: &Schematic) -> u64 {
+        return self
+            .part_numbers(schematic)
+            .into_iter()
+            .map(|id| schematic.part(id).value as u64)
+            .sum();
+    }
+
+    /// Sum of every gear ratio (part 2)
+    pub fn gear_total(&self, schematic: &Schematic) -> Result<u64, String> {
+        let mut total: u64 = 0;
+        for gear in self.gears(schematic)? {
+            total = total
+                .checked_add(gear.ratio)
+                .ok_or("Sum of the gear ratios overflows u64")?;
+        }
+        return Ok(total);
+    }
+}
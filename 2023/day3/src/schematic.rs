@@ -0,0 +1,273 @@
+use std::fmt;
+
+use aoc_common::grid::Position;
+use aoc_common::{Grid, ParseError};
+
+/// Index of a part number in its schematic's arena. Ids are assigned in reading order,
+/// so the same input always gives the same ids. Editing a number gives it a new id; the
+/// ids of numbers whose digits are unchanged, even right next to an edit, never change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PartId(pub usize);
+
+/// Where a part number is written: `len` digits starting at (`row`, `col`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub row: usize,
+    pub col: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn contains(&self, pos: Position) -> bool {
+        return pos.0 == self.row && pos.1 >= self.col && pos.1 < self.col + self.len;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: u32,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Symbol(char),
+    /// One digit of a part number
+    Part(PartId),
+}
+
+impl Cell {
+    /// The cell for `c`, before digits are grouped into part numbers
+    fn from_char(c: char) -> Cell {
+        match c {
+            '.' => return Cell::Empty,
+            c if c.is_ascii_digit() => return Cell::Empty,
+            c => return Cell::Symbol(c),
+        }
+    }
+}
+
+/// The runs of digits in `row` that overlap `cols`, as (start, length, value)
+fn digit_runs(
+    row: &[char],
+    cols: std::ops::Range<usize>,
+) -> Vec<(usize, usize, Result<u32, String>)> {
+    let mut runs = Vec::new();
+    // A run that starts left of `cols` but reaches into it is included whole
+    let mut col = cols.start;
+    let in_run = |col: usize| col < row.len() && row[col].is_ascii_digit();
+    while col > 0 && in_run(col) && in_run(col - 1) {
+        col -= 1;
+    }
+
+    while col < cols.end.min(row.len()) {
+        if !row[col].is_ascii_digit() {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < row.len() && row[col].is_ascii_digit() {
+            col += 1;
+        }
+        let num: String = row[start..col].iter().collect();
+        let value = num
+            .parse()
+            .map_err(|e| format!("Could not parse \"{}\": {}", num, e));
+        runs.push((start, col - start, value));
+    }
+    return runs;
+}
+
+/// Engine schematic, coords are (row, col)
+pub struct Schematic {
+    /// The schematic as written
+    chars: Grid<char>,
+    grid: Grid<Cell>,
+    /// Indexed by id. Numbers removed by edits leave a `None`, so later ids stay put.
+    parts: Vec<Option<PartNumber>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+        let chars: Grid<char> = Grid::parse(input, Ok)?;
+        let grid = chars.map(|&c| Cell::from_char(c));
+        let mut schematic = Schematic {
+            chars,
+            grid,
+            parts: Vec::new(),
+        };
+
+        let lines = aoc_common::numbered_lines(input);
+        for (row, line) in lines.iter().enumerate() {
+            let digits = schematic.chars.row(row).unwrap().to_vec();
+
+            for (col, len, value) in digit_runs(&digits, 0..digits.len()) {
+                let value = value.map_err(|message| {
+                    let num: String = digits[col..col + len].iter().collect();
+                    ParseError::new(line.text, row, col, &num, message)
+                })?;
+                schematic.add_part(row, col, len, value);
+            }
+        }
+
+        return Ok(schematic);
+    }
+
+    fn add_part(&mut self, row: usize, col: usize, len: usize, value: u32) -> PartId {
+        let id = PartId(self.parts.len());
+        self.parts.push(Some(PartNumber {
+            value,
+            span: Span { row, col, len },
+        }));
+        (col..col + len).for_each(|c| self.grid[(row, c)] = Cell::Part(id));
+        return id;
+    }
+
+    /// Writes `c` at `pos`, regrouping the digits around it. Returns the ids of the part
+    /// numbers that were removed and of the ones that replaced them.
+    pub(crate) fn set_char(
+        &mut self,
+        pos: Position,
+        c: char,
+    ) -> Result<(Vec<PartId>, Vec<PartId>), String> {
+        if !self.grid.contains(pos) {
+            return Err(format!("{:?} is outside the schematic", pos));
+        }
+        if c.is_whitespace() {
+            return Err(format!("{:?} can't be part of a schematic", c));
+        }
+
+        // Every number that touches `pos` in its row is looked at again, since the edit can
+        // join or split numbers
+        let mut touching: Vec<PartId> = [pos.1.checked_sub(1), Some(pos.1), Some(pos.1 + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|col| self.part_at((pos.0, col)))
+            .collect();
+        touching.dedup();
+
+        let mut row: Vec<char> = self.chars.row(pos.0).unwrap().to_vec();
+        row[pos.1] = c;
+        let mut runs = digit_runs(&row, pos.1.saturating_sub(1)..pos.1 + 2);
+        if let Some((_, _, Err(e))) = runs.iter().find(|run| run.2.is_err()) {
+            return Err(e.clone());
+        }
+
+        // Neighbouring numbers the edit left exactly as they were keep their ids
+        let unchanged = |part: &PartNumber, run: &(usize, usize, Result<u32, String>)| {
+            return !part.span.contains(pos)
+                && (run.0, run.1, run.2.as_ref().ok())
+                    == (part.span.col, part.span.len, Some(&part.value));
+        };
+        let (kept, removed): (Vec<PartId>, Vec<PartId>) = touching
+            .into_iter()
+            .partition(|&id| runs.iter().any(|run| unchanged(self.part(id), run)));
+        runs.retain(|run| !kept.iter().any(|&id| unchanged(self.part(id), run)));
+
+        for &id in &removed {
+            let span = self.part(id).span;
+            (span.col..span.col + span.len)
+                .for_each(|col| self.grid[(span.row, col)] = Cell::Empty);
+            self.parts[id.0] = None;
+        }
+        self.chars[pos] = c;
+        self.grid[pos] = Cell::from_char(c);
+
+        let added = runs
+            .into_iter()
+            .map(|(col, len, value)| self.add_part(pos.0, col, len, value.unwrap()))
+            .collect();
+        return Ok((removed, added));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.grid.width();
+    }
+
+    pub fn height(&self) -> usize {
+        return self.grid.height();
+    }
+
+    /// Every part number with its id, in id order
+    pub fn parts(&self) -> impl Iterator<Item = (PartId, &PartNumber)> + '_ {
+        return self
+            .parts
+            .iter()
+            .enumerate()
+            .filter_map(|(i, part)| Some((PartId(i), part.as_ref()?)));
+    }
+
+    /// The part number with id `id`. Panics if an edit has removed it.
+    pub fn part(&self, id: PartId) -> &PartNumber {
+        return self.parts[id.0]
+            .as_ref()
+            .unwrap_or_else(|| panic!("{:?} was removed by an edit", id));
+    }
+
+    /// Every symbol with its position, in reading order
+    pub fn symbols(&self) -> impl Iterator<Item = (Position, char)> + '_ {
+        return self.grid.iter().filter_map(|(pos, cell)| match cell {
+            Cell::Symbol(c) => Some((pos, *c)),
+            _ => None,
+        });
+    }
+
+    /// The character written at `pos`
+    pub fn char_at(&self, pos: Position) -> Option<char> {
+        return self.chars.get(pos).copied();
+    }
+
+    pub fn symbol_at(&self, pos: Position) -> Option<char> {
+        match self.grid.get(pos) {
+            Some(Cell::Symbol(c)) => return Some(*c),
+            _ => return None,
+        }
+    }
+
+    pub fn part_at(&self, pos: Position) -> Option<PartId> {
+        match self.grid.get(pos) {
+            Some(Cell::Part(id)) => return Some(*id),
+            _ => return None,
+        }
+    }
+
+    /// Ids of the part numbers touching `pos`, diagonals included, in id order
+    pub fn parts_adjacent_to(&self, pos: Position) -> Vec<PartId> {
+        let mut ids: Vec<PartId> = self
+            .grid
+            .neighbours8(pos)
+            .filter_map(|p| self.part_at(p))
+            .collect();
+        ids.sort();
+        ids.dedup();
+        return ids;
+    }
+
+    /// Symbols touching any digit of part `id`, diagonals included, in reading order
+    pub fn symbols_adjacent_to(&self, id: PartId) -> Vec<(Position, char)> {
+        let span = self.part(id).span;
+        return self
+            .grid
+            .region(
+                span.row.saturating_sub(1)..span.row + 2,
+                span.col.saturating_sub(1)..span.col + span.len + 1,
+            )
+            .filter_map(|(pos, cell)| match cell {
+                Cell::Symbol(c) => Some((pos, *c)),
+                _ => None,
+            })
+            .collect();
+    }
+}
+
+impl fmt::Display for Schematic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height() {
+            let line: String = self.chars.row(row).unwrap().iter().collect();
+            writeln!(f, "{}", line)?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,154 @@
+use day3::{Aggregation, Count, Delta, LiveSchematic, Neighbourhood, PartId, Rules, Schematic};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+#[test]
+fn edits_report_their_delta() {
+    let mut live =
+        LiveSchematic::new(Schematic::parse(EXAMPLE).unwrap(), Rules::default()).unwrap();
+    assert_eq!((live.part1(), live.part2()), (4361, 467835));
+
+    // A symbol next to 114 makes it a part number
+    let delta = live.apply_edit((1, 6), '#').unwrap();
+    assert_eq!(
+        delta,
+        Delta {
+            part1: 114,
+            part2: 0
+        }
+    );
+
+    // Overwriting the `*` after 617 makes 6177, with no symbol next to it
+    let delta = live.apply_edit((4, 3), '7').unwrap();
+    assert_eq!(
+        delta,
+        Delta {
+            part1: -617,
+            part2: 0
+        }
+    );
+
+    // A third number next to the `*` at (8, 5) stops it being a gear
+    let delta = live.apply_edit((7, 4), '1').unwrap();
+    assert_eq!(
+        delta,
+        Delta {
+            part1: 1,
+            part2: -755 * 598
+        }
+    );
+
+    assert!(live.apply_edit((10, 0), '1').is_err());
+    assert!(live.apply_edit((0, 0), '\n').is_err());
+}
+
+#[test]
+fn ids_of_untouched_numbers_are_kept() {
+    let mut live =
+        LiveSchematic::new(Schematic::parse(EXAMPLE).unwrap(), Rules::default()).unwrap();
+    live.apply_edit((0, 1), '.').unwrap();
+
+    let schematic = live.schematic();
+    assert_eq!(schematic.part(PartId(1)).value, 114);
+    let values: Vec<u32> = schematic.parts().map(|(_, p)| p.value).take(3).collect();
+    assert_eq!(values, vec![114, 35, 633]);
+    // Splitting 467 gives its halves the next free ids
+    let halves: Vec<(PartId, u32)> = schematic
+        .parts()
+        .skip(9)
+        .map(|(id, p)| (id, p.value))
+        .collect();
+    assert_eq!(halves, vec![(PartId(10), 4), (PartId(11), 7)]);
+
+    // A symbol right next to 114 leaves it as it was
+    live.apply_edit((0, 4), '#').unwrap();
+    let schematic = live.schematic();
+    assert_eq!(schematic.part_at((0, 5)), Some(PartId(1)));
+    assert_eq!(schematic.parts().count(), 11);
+}
+
+/// Small deterministic generator, so failures are reproducible
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        return ((self.0 >> 33) % bound as u64) as usize;
+    }
+}
+
+/// After every edit the totals match solving the edited schematic from scratch.
+fn check_random_edits(rules: Rules, seed: u64) {
+    let mut live = LiveSchematic::new(Schematic::parse(EXAMPLE).unwrap(), rules.clone()).unwrap();
+    let mut rng = Lcg(seed);
+    let chars = ['.', '.', '.', '1', '2', '5', '9', '0', '*', '#', '$'];
+
+    for _ in 0..500 {
+        let pos = (rng.next(10), rng.next(10));
+        let c = chars[rng.next(chars.len())];
+        let before = (live.part1() as i64, live.part2() as i64);
+        let delta = live.apply_edit(pos, c).unwrap();
+
+        let fresh = Schematic::parse(&live.schematic().to_string()).unwrap();
+        let expected = (rules.part_total(&fresh), rules.gear_total(&fresh).unwrap());
+        assert_eq!(
+            (live.part1(), live.part2()),
+            expected,
+            "after {:?} at {:?}\n{}",
+            c,
+            pos,
+            fresh
+        );
+        assert_eq!(
+            (before.0 + delta.part1, before.1 + delta.part2),
+            (expected.0 as i64, expected.1 as i64)
+        );
+    }
+}
+
+#[test]
+fn random_edits_match_a_full_recount() {
+    check_random_edits(Rules::default(), 1);
+    check_random_edits(
+        Rules {
+            neighbourhood: Neighbourhood::Radius(2),
+            gear_parts: Count::AtLeast(1),
+            aggregation: Aggregation::Sum,
+            ..Rules::default()
+        },
+        2,
+    );
+    check_random_edits(
+        Rules {
+            neighbourhood: Neighbourhood::VonNeumann,
+            gear_parts: Count::Between(1, 3),
+            aggregation: Aggregation::Max,
+            ..Rules::default()
+        },
+        3,
+    );
+}
+
+#[test]
+fn an_edit_whose_ratio_overflows_is_undone() {
+    let rules = Rules {
+        gear_parts: Count::Exactly(3),
+        ..Rules::default()
+    };
+    let input = "4000000000*4000000000\n.........4000000000..\n";
+    assert!(LiveSchematic::new(Schematic::parse(input).unwrap(), rules.clone()).is_err());
+
+    let input = "4000000000.4000000000\n.........4000000000..\n";
+    let mut live = LiveSchematic::new(Schematic::parse(input).unwrap(), rules).unwrap();
+    assert!(live.apply_edit((0, 10), '*').is_err());
+    assert_eq!(live.schematic().to_string(), input);
+    assert_eq!((live.part1(), live.part2()), (0, 0));
+
+    // Only two numbers are next to this one, so it isn't a gear
+    let delta = live.apply_edit((1, 20), '*').unwrap();
+    assert_eq!(delta.part1, 4000000000);
+    assert_eq!(live.part2(), 0);
+}
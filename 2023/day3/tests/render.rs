@@ -0,0 +1,39 @@
+use day3::render::{Annotation, Style};
+use day3::{Rules, Schematic};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+#[test]
+fn cells_are_styled_by_what_counts() {
+    let schematic = Schematic::parse(EXAMPLE).unwrap();
+    let annotation = Annotation::new(&schematic, &Rules::default()).unwrap();
+
+    assert_eq!(annotation.style((0, 0)), Style::GearPart); // 467
+    assert_eq!(annotation.style((0, 5)), Style::Uncounted); // 114
+    assert_eq!(annotation.style((2, 6)), Style::Counted); // 633
+    assert_eq!(annotation.style((1, 3)), Style::Gear);
+    assert_eq!(annotation.style((4, 3)), Style::Symbol); // a `*` next to only 617
+    assert_eq!(annotation.style((0, 3)), Style::Plain);
+
+    assert_eq!(
+        annotation.gear_lines(),
+        vec![
+            "* at (1, 3): 467, 35 -> 16345",
+            "* at (8, 5): 755, 598 -> 451490"
+        ]
+    );
+}
+
+#[test]
+fn html_marks_each_run() {
+    let schematic = Schematic::parse(EXAMPLE).unwrap();
+    let html = Annotation::new(&schematic, &Rules::default())
+        .unwrap()
+        .to_html();
+
+    assert!(html.contains(
+        "<span class=\"gear-part\">467</span>..<span class=\"uncounted\">114</span>..\n"
+    ));
+    assert!(html.contains("<span class=\"gear\">*</span>"));
+    assert!(html.contains("<li>* at (8, 5): 755, 598 -&gt; 451490</li>"));
+}
@@ -0,0 +1,76 @@
+use day3::{Aggregation, Count, Neighbourhood, Rules, Schematic, Symbols};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+fn example() -> Schematic {
+    return Schematic::parse(EXAMPLE).unwrap();
+}
+
+#[test]
+fn default_rules_are_the_puzzle() {
+    let rules = Rules::default();
+    assert_eq!(rules.part_total(&example()), 4361);
+    assert_eq!(rules.gear_total(&example()).unwrap(), 467835);
+}
+
+#[test]
+fn orthogonal_neighbourhood() {
+    let rules = Rules {
+        neighbourhood: Neighbourhood::VonNeumann,
+        ..Rules::default()
+    };
+    assert_eq!(rules.part_total(&example()), 35 + 633 + 617 + 664 + 598);
+    assert!(rules.gears(&example()).unwrap().is_empty());
+}
+
+#[test]
+fn larger_radius_and_chosen_symbols() {
+    let rules = Rules {
+        part_symbols: Symbols::Only(vec!['#']),
+        neighbourhood: Neighbourhood::Radius(2),
+        ..Rules::default()
+    };
+    assert_eq!(rules.part_total(&example()), 633 + 58);
+}
+
+#[test]
+fn gear_counts_and_aggregations() {
+    let schematic = example();
+    let with = |gear_parts, aggregation| {
+        let rules = Rules {
+            gear_parts,
+            aggregation,
+            ..Rules::default()
+        };
+        return rules.gear_total(&schematic).unwrap();
+    };
+
+    assert_eq!(
+        with(Count::AtLeast(1), Aggregation::Sum),
+        467 + 35 + 617 + 755 + 598
+    );
+    assert_eq!(with(Count::Exactly(2), Aggregation::Max), 467 + 755);
+    assert_eq!(
+        with(Count::Between(1, 2), Aggregation::Product),
+        467 * 35 + 617 + 755 * 598
+    );
+    assert_eq!(with(Count::AtLeast(3), Aggregation::Product), 0);
+}
+
+#[test]
+fn overflowing_ratios_are_errors() {
+    let schematic = Schematic::parse("4000000000*4000000000\n.........4000000000..\n").unwrap();
+    let rules = Rules {
+        gear_parts: Count::Exactly(3),
+        ..Rules::default()
+    };
+    let error = rules.gear_total(&schematic).unwrap_err();
+    assert!(error.contains("parts 0, 1, 2"), "{}", error);
+
+    // The sum fits, though it would not as a product
+    let rules = Rules {
+        aggregation: Aggregation::Sum,
+        ..rules
+    };
+    assert_eq!(rules.gear_total(&schematic), Ok(12000000000));
+}
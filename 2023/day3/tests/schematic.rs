@@ -0,0 +1,47 @@
+use day3::{PartId, Schematic, Span};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+/// Ids come from the schematic itself, so parsing the same input twice gives the same ids.
+#[test]
+fn ids_are_per_schematic() {
+    let first = Schematic::parse(EXAMPLE).unwrap();
+    let second = Schematic::parse(EXAMPLE).unwrap();
+    assert!(first.parts().eq(second.parts()));
+
+    assert_eq!(first.parts().count(), 10);
+    assert_eq!(first.part(PartId(0)).value, 467);
+    assert_eq!(
+        first.part(PartId(0)).span,
+        Span {
+            row: 0,
+            col: 0,
+            len: 3
+        }
+    );
+    assert_eq!(first.part(PartId(9)).value, 598);
+}
+
+#[test]
+fn adjacency_both_ways() {
+    let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+    // The `*` at (1, 3) joins 467 and 35
+    let parts: Vec<u32> = schematic
+        .parts_adjacent_to((1, 3))
+        .into_iter()
+        .map(|id| schematic.part(id).value)
+        .collect();
+    assert_eq!(parts, vec![467, 35]);
+
+    let id_of = |value| {
+        let (id, _) = schematic.parts().find(|(_, p)| p.value == value).unwrap();
+        return id;
+    };
+    assert_eq!(
+        schematic.symbols_adjacent_to(id_of(617)),
+        vec![((4, 3), '*')]
+    );
+    assert!(schematic.symbols_adjacent_to(id_of(114)).is_empty());
+    assert!(schematic.symbols_adjacent_to(id_of(58)).is_empty());
+}
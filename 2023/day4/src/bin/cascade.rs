@@ -0,0 +1,97 @@
+use std::fs;
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day4::cascade::Cascade;
+use day4::{BigCount, Counter, Day4};
+use serde::Serialize;
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str =
+    "Usage: cascade [--dot OUTPUT] [--json OUTPUT] [--top N] [--count u64|u128|big] [INPUT]";
+
+struct Args {
+    dot: Option<String>,
+    json: Option<String>,
+    top: usize,
+    /// Type to count copies in
+    count: String,
+    path: String,
+}
+
+fn run<C: Counter + Serialize>(args: &Args) -> Result<(), String> {
+    let contents = aoc_common::load_input(&args.path)?;
+    let cards = Day4::parse(&contents).map_err(|e| e.with_file(&args.path).to_string())?;
+    let cascade = Cascade::<C>::new(&cards)?;
+
+    let write = |path: &str, contents: String| {
+        fs::write(path, contents).map_err(|e| format!("Unable to write {}: {}", path, e))?;
+        println!("Wrote {}", path);
+        return Ok::<(), String>(());
+    };
+    if let Some(path) = &args.dot {
+        write(path, cascade.to_dot())?;
+    }
+    if let Some(path) = &args.json {
+        write(path, cascade.to_json())?;
+    }
+
+    println!("{:>6} {:>12} {:>12}", "card", "direct", "total");
+    for contribution in cascade.contributions()?.iter().take(args.top) {
+        println!(
+            "{:>6} {:>12} {:>12}",
+            contribution.card, contribution.direct, contribution.total
+        );
+    }
+    return Ok(());
+}
+
+/// Shows which cards won the most copies, and exports the cascade as DOT or JSON
+fn main() -> ExitCode {
+    let mut argv = std::env::args().skip(1);
+    let mut args = Args {
+        dot: None,
+        json: None,
+        top: 10,
+        count: "u64".to_string(),
+        path: INPUT_FILE.to_string(),
+    };
+
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--dot" | "--json" | "--count" => match argv.next() {
+                Some(output) if arg == "--dot" => args.dot = Some(output),
+                Some(output) if arg == "--json" => args.json = Some(output),
+                Some(count) => args.count = count,
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--top" => match argv.next().and_then(|n| n.parse().ok()) {
+                Some(n) => args.top = n,
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => args.path = arg,
+        }
+    }
+
+    let result = match args.count.as_str() {
+        "u64" => run::<u64>(&args),
+        "u128" => run::<u128>(&args),
+        "big" => run::<BigCount>(&args),
+        _ => Err(format!("Unknown count type \"{}\"\n{}", args.count, USAGE)),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+use crate::{copies, Counter, Scratchcard};
+
+/// One card of the deck, with how many of it we end up with
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Node<C = u64> {
+    pub card: u32,
+    pub matches: usize,
+    pub copies: C,
+}
+
+/// `from` won `copies` copies of `to`: one for each copy of `from`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Edge<C = u64> {
+    pub from: u32,
+    pub to: u32,
+    pub copies: C,
+}
+
+/// How many copies a card is responsible for
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Contribution<C = u64> {
+    pub card: u32,
+    /// Copies its own copies won
+    pub direct: C,
+    /// Copies it won, the copies those won, and so on
+    pub total: C,
+}
+
+/// Which cards won copies of which, as a DAG from each card to the cards after it, counted
+/// in `C`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Cascade<C = u64> {
+    pub nodes: Vec<Node<C>>,
+    pub edges: Vec<Edge<C>>,
+}
+
+impl<C: Counter> Cascade<C> {
+    pub fn new(cards: &[Scratchcard]) -> Result<Cascade<C>, String> {
+        let copies = copies::<C>(cards)?;
+        let nodes: Vec<Node<C>> = cards
+            .iter()
+            .zip(copies)
+            .map(|(card, copies)| Node {
+                card: card.num,
+                matches: card.matches(),
+                copies,
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let last = (i + node.matches).min(nodes.len() - 1);
+            for won in &nodes[i + 1..=last] {
+                edges.push(Edge {
+                    from: node.card,
+                    to: won.card,
+                    copies: node.copies.clone(),
+                });
+            }
+        }
+
+        return Ok(Cascade { nodes, edges });
+    }
+
+    /// Number of cards each card's edges lead to, by node index
+    fn fan_out(&self) -> Vec<usize> {
+        return self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| node.matches.min(self.nodes.len() - 1 - i))
+            .collect();
+    }
+
+    /// What each card contributes, most copies first, ties in card order
+    pub fn contributions(&self) -> Result<Vec<Contribution<C>>, String> {
+        let fan_out = self.fan_out();
+        let overflow = |card: u32| format!("Copies won by card {} overflow {}", card, C::NAME);
+
+        // Cards won by a single copy of each card, directly or further down the cascade
+        let mut descendants = vec![C::from_u32(0); self.nodes.len()];
+        for i in (0..self.nodes.len()).rev() {
+            for j in i + 1..i + 1 + fan_out[i] {
+                descendants[i] = descendants[j]
+                    .try_add(&C::from_u32(1))
+                    .and_then(|d| d.try_add(&descendants[i]))
+                    .ok_or_else(|| overflow(self.nodes[i].card))?;
+            }
+        }
+
+        let mut contributions = Vec::with_capacity(self.nodes.len());
+        for (i, node) in self.nodes.iter().enumerate() {
+            contributions.push(Contribution {
+                card: node.card,
+                direct: node
+                    .copies
+                    .try_mul(&C::from_u32(fan_out[i] as u32))
+                    .ok_or_else(|| overflow(node.card))?,
+                total: node
+                    .copies
+                    .try_mul(&descendants[i])
+                    .ok_or_else(|| overflow(node.card))?,
+            });
+        }
+        contributions.sort_by(|a, b| b.total.cmp(&a.total).then(a.card.cmp(&b.card)));
+        return Ok(contributions);
+    }
+
+    /// The cascade for Graphviz, with edges labelled by the copies they carry and nodes
+    /// by the copies they end up with
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph cascade {\n    rankdir=LR;\n");
+        for node in &self.nodes {
+            out += &format!(
+                "    card{} [label=\"Card {}\\n{} copies\"];\n",
+                node.card, node.card, node.copies
+            );
+        }
+        for edge in &self.edges {
+            out += &format!(
+                "    card{} -> card{} [label=\"{}\"];\n",
+                edge.from, edge.to, edge.copies
+            );
+        }
+        out += "}\n";
+        return out;
+    }
+
+    pub fn to_json(&self) -> String
+    where
+        C: Serialize,
+    {
+        return serde_json::to_string_pretty(self).unwrap() + "\n";
+    }
+}
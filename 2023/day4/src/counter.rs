@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// A number type the scratchcard engine can count in. Operations that don't fit give `None`
+/// instead of wrapping.
+pub trait Counter: Clone + Ord + fmt::Display {
+    /// Name of the type, for errors
+    const NAME: &'static str;
+
+    fn from_u32(n: u32) -> Self;
+
+    fn try_add(&self, other: &Self) -> Option<Self>;
+
+    fn try_mul(&self, other: &Self) -> Option<Self>;
+
+    /// 2 to the power `exp`
+    fn try_pow2(exp: u32) -> Option<Self>;
+}
+
+macro_rules! primitive_counter {
+    ($t:ty) => {
+        impl Counter for $t {
+            const NAME: &'static str = stringify!($t);
+
+            fn from_u32(n: u32) -> Self {
+                return n as $t;
+            }
+
+            fn try_add(&self, other: &Self) -> Option<Self> {
+                return <$t>::checked_add(*self, *other);
+            }
+
+            fn try_mul(&self, other: &Self) -> Option<Self> {
+                return <$t>::checked_mul(*self, *other);
+            }
+
+            fn try_pow2(exp: u32) -> Option<Self> {
+                return <$t>::checked_pow(2, exp);
+            }
+        }
+    };
+}
+
+primitive_counter!(u64);
+primitive_counter!(u128);
+
+/// An unsigned integer of any size, which never overflows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigCount {
+    /// Base 2^32 digits, least significant first, without trailing zeros
+    limbs: Vec<u32>,
+}
+
+impl BigCount {
+    fn trim(mut self) -> BigCount {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        return self;
+    }
+}
+
+impl From<u128> for BigCount {
+    fn from(mut n: u128) -> BigCount {
+        let mut limbs = Vec::new();
+        while n > 0 {
+            limbs.push(n as u32);
+            n >>= 32;
+        }
+        return BigCount { limbs };
+    }
+}
+
+impl Counter for BigCount {
+    const NAME: &'static str = "BigCount";
+
+    fn from_u32(n: u32) -> Self {
+        return BigCount::from(n as u128);
+    }
+
+    fn try_add(&self, other: &Self) -> Option<Self> {
+        let len = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+        for i in 0..len {
+            let a = self.limbs.get(i).copied().unwrap_or(0) as u64;
+            let b = other.limbs.get(i).copied().unwrap_or(0) as u64;
+            let sum = a + b + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        return Some(BigCount { limbs }.trim());
+    }
+
+    fn try_mul(&self, other: &Self) -> Option<Self> {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        return Some(BigCount { limbs }.trim());
+    }
+
+    fn try_pow2(exp: u32) -> Option<Self> {
+        let mut limbs = vec![0u32; exp as usize / 32];
+        limbs.push(1 << (exp % 32));
+        return Some(BigCount { limbs });
+    }
+}
+
+impl Ord for BigCount {
+    fn cmp(&self, other: &BigCount) -> Ordering {
+        // Without trailing zeros, more limbs means a larger number
+        return self
+            .limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()));
+    }
+}
+
+impl PartialOrd for BigCount {
+    fn partial_cmp(&self, other: &BigCount) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/// Serialized as a decimal string, since JSON numbers that large lose precision
+impl Serialize for BigCount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.collect_str(self);
+    }
+}
+
+impl fmt::Display for BigCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off nine decimal digits at a time, least significant first
+        let mut limbs = self.limbs.clone();
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                let value = (remainder << 32) | *limb as u64;
+                *limb = (value / 1_000_000_000) as u32;
+                remainder = value % 1_000_000_000;
+            }
+            chunks.push(remainder);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+
+        let mut text = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            text += &format!("{:09}", chunk);
+        }
+        return f.pad(&text);
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{Line, ParseError, Solution};
+
+pub mod cascade;
+pub mod counter;
+
+pub use counter::{BigCount, Counter};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub num: u32,
+    pub winners: Vec<u32>,
+    pub haves: Vec<u32>,
+}
+
+impl Scratchcard {
+    /// Parses `Card N: winners | haves`, with the numbers separated by any whitespace
+    pub fn parse(line: &Line) -> Result<Scratchcard, ParseError> {
+        let (card, numbers) = line.split_once(line.text, ":")?;
+        let num = line.strip_prefix(card, "Card")?;
+        if !num.starts_with(char::is_whitespace) {
+            return Err(line.error(num, "Expected a space after \"Card\""));
+        }
+        let num = line.parse::<u32>(num.trim())?;
+
+        let (winners, haves) = line.split_once(numbers, "|")?;
+        return Ok(Scratchcard {
+            num,
+            winners: line.parse_all(winners)?,
+            haves: line.parse_all(haves)?,
+        });
+    }
+
+    /// How many of the numbers we have are winning numbers
+    pub fn matches(&self) -> usize {
+        let winners: HashSet<u32> = self.winners.iter().copied().collect();
+        return self.haves.iter().filter(|n| winners.contains(n)).count();
+    }
+}
+
+/// Total points of the cards: 1 for a card's first match, doubled for each match after
+pub fn points<C: Counter>(cards: &[Scratchcard]) -> Result<C, String> {
+    let mut total = C::from_u32(0);
+
+    for card in cards {
+        let num_in_both = card.matches();
+        if num_in_both > 0 {
+            total = C::try_pow2((num_in_both - 1) as u32)
+                .and_then(|points| total.try_add(&points))
+                .ok_or_else(|| format!("Points up to card {} overflow {}", card.num, C::NAME))?;
+        }
+    }
+
+    return Ok(total);
+}
+
+/// How many of each card we end up with, when every card wins one copy of each of the
+/// next `matches` cards. Copies never go past the last card.
+pub fn copies<C: Counter>(cards: &[Scratchcard]) -> Result<Vec<C>, String> {
+    let mut copies = vec![C::from_u32(1); cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let won = (i + 1)..(i + 1 + card.matches()).min(cards.len());
+        let count = copies[i].clone();
+        for j in won {
+            copies[j] = copies[j]
+                .try_add(&count)
+                .ok_or_else(|| format!("Copies of card {} overflow {}", cards[j].num, C::NAME))?;
+        }
+    }
+
+    return Ok(copies);
+}
+
+/// How many cards we end up with, originals and copies
+pub fn total_copies<C: Counter>(cards: &[Scratchcard]) -> Result<C, String> {
+    let mut total = C::from_u32(0);
+    for count in copies::<C>(cards)? {
+        total = total
+            .try_add(&count)
+            .ok_or_else(|| format!("Total number of cards overflows {}", C::NAME))?;
+    }
+    return Ok(total);
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Scratchcard>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return aoc_common::numbered_lines(input)
+            .iter()
+            .map(Scratchcard::parse)
+            .collect();
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return points::<u64>(input);
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return total_copies::<u64>(input);
+    }
+}
@@ -1,41 +1,63 @@
-use std::collections::HashSet;
-use std::collections::VecDeque;
-use std::fs;
+use std::process::ExitCode;
 
-const INPUT_FILE: &str = "src/input.txt";
+use aoc_common::Solution;
+use day4::{BigCount, Counter, Day4};
 
-fn main() {
-    let contents = fs::read_to_string(INPUT_FILE).expect("Unable to read the file");
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: day4 [--count u64|u128|big] [INPUT]";
 
-    let mut part1_score: i32 = 0;
-    let mut part2_count: i32 = 0;
-    let mut copy_stack: VecDeque<i32> = VecDeque::from([1; 206]);
-
-    for line in contents.split("\n") {
-
-        let winning_nums: HashSet<i32> = HashSet::from_iter(
-            line[10..39]
-                .split(" ")
-                .filter_map(|x| x.parse::<i32>().ok())
-        );
-        let have_nums: HashSet<i32> = HashSet::from_iter(
-            line[42..]
-                .split(" ")
-                .filter_map(|x| x.parse::<i32>().ok())
-        );
-
-        let num_in_both = winning_nums.intersection(&have_nums).count() as i32;
+fn solve<C: Counter>(path: &str) -> Result<(C, C), String> {
+    let contents = aoc_common::load_input(path)?;
+    let cards = Day4::parse(&contents).map_err(|e| e.with_file(path).to_string())?;
+    return Ok((day4::points(&cards)?, day4::total_copies(&cards)?));
+}
 
-        if num_in_both > 0 {
-            part1_score += 2_i32.pow((num_in_both - 1) as u32);
+fn print<C: Counter>(answers: Result<(C, C), String>) -> ExitCode {
+    match answers {
+        Ok((part1, part2)) => {
+            aoc_common::print_answer(1, part1);
+            aoc_common::print_answer(2, part2);
+            return ExitCode::SUCCESS;
         }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+}
 
-        let copies = copy_stack.pop_front().unwrap();
-        part2_count += copies;
-
-        copy_stack.range_mut(..num_in_both as usize).for_each(|i| *i += copies);
+/// Solves the puzzle, optionally counting in a wider type than `Day4`'s u64, for decks
+/// too large for it
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut count = None;
+    let mut path = INPUT_FILE.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--count" => match args.next() {
+                Some(name) => count = Some(name),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => path = arg,
+        }
     }
 
-    println!("Part 1: {:?}", part1_score);
-    println!("Part 2: {:?}", part2_count);
+    match count.as_deref() {
+        None => return aoc_common::run::<Day4>(&path),
+        Some("u64") => return print(solve::<u64>(&path)),
+        Some("u128") => return print(solve::<u128>(&path)),
+        Some("big") => return print(solve::<BigCount>(&path)),
+        Some(name) => {
+            eprintln!("Error: Unknown count type \"{}\"\n{}", name, USAGE);
+            return ExitCode::FAILURE;
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use aoc_common::Solution;
+use day4::cascade::{Cascade, Contribution};
+use day4::{BigCount, Counter, Day4, Scratchcard};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+fn example() -> Cascade {
+    return Cascade::new(&Day4::parse(EXAMPLE).unwrap()).unwrap();
+}
+
+#[test]
+fn edges_carry_one_copy_per_copy_of_the_winner() {
+    let cascade = example();
+    let edges: Vec<(u32, u32, u64)> = cascade
+        .edges
+        .iter()
+        .map(|e| (e.from, e.to, e.copies))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            (1, 2, 1),
+            (1, 3, 1),
+            (1, 4, 1),
+            (1, 5, 1),
+            (2, 3, 2),
+            (2, 4, 2),
+            (3, 4, 4),
+            (3, 5, 4),
+            (4, 5, 8),
+        ]
+    );
+
+    // Every copy but the originals arrives along an edge
+    for node in &cascade.nodes {
+        let incoming: u64 = cascade
+            .edges
+            .iter()
+            .filter(|e| e.to == node.card)
+            .map(|e| e.copies)
+            .sum();
+        assert_eq!(node.copies, 1 + incoming);
+    }
+}
+
+#[test]
+fn contributions_follow_the_whole_cascade() {
+    let contributions = example().contributions().unwrap();
+    assert_eq!(
+        contributions[..3],
+        [
+            Contribution {
+                card: 1,
+                direct: 4,
+                total: 14
+            },
+            Contribution {
+                card: 2,
+                direct: 4,
+                total: 12
+            },
+            Contribution {
+                card: 3,
+                direct: 8,
+                total: 12
+            },
+        ]
+    );
+    assert_eq!(contributions.iter().map(|c| c.direct).sum::<u64>(), 30 - 6);
+}
+
+#[test]
+fn exports() {
+    let cascade = example();
+
+    let dot = cascade.to_dot();
+    assert!(dot.starts_with("digraph cascade {\n"));
+    assert!(dot.contains("    card5 [label=\"Card 5\\n14 copies\"];\n"));
+    assert!(dot.contains("    card4 -> card5 [label=\"8\"];\n"));
+
+    let json: serde_json::Value = serde_json::from_str(&cascade.to_json()).unwrap();
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 6);
+    assert_eq!(json["nodes"][4]["copies"], 14);
+    let edge = &json["edges"][8];
+    assert_eq!(
+        (&edge["from"], &edge["to"], &edge["copies"]),
+        (&4.into(), &5.into(), &8.into())
+    );
+}
+
+#[test]
+fn large_decks_need_a_wider_count() {
+    // 3000 cards that each win the next two: the copies grow like the Fibonacci numbers
+    let cards: Vec<Scratchcard> = (1..=3000)
+        .map(|num| Scratchcard {
+            num,
+            winners: vec![1, 2],
+            haves: vec![1, 2, 99],
+        })
+        .collect();
+    assert_eq!(
+        Cascade::<u64>::new(&cards).unwrap_err(),
+        "Copies of card 92 overflow u64"
+    );
+    assert!(Cascade::<u128>::new(&cards).is_err());
+
+    let cascade = Cascade::<BigCount>::new(&cards).unwrap();
+    let contributions = cascade.contributions().unwrap();
+    assert_eq!(contributions.len(), 3000);
+    assert!(contributions.windows(2).all(|w| w[0].total >= w[1].total));
+    assert_eq!(cascade.nodes[2].copies, BigCount::from_u32(4));
+
+    // Too large for a JSON number, so they are written as strings
+    let json: serde_json::Value = serde_json::from_str(&cascade.to_json()).unwrap();
+    assert_eq!(json["nodes"][2]["copies"], "4");
+}
@@ -0,0 +1,66 @@
+use day4::{points, total_copies, BigCount, Counter, Scratchcard};
+
+/// `n` cards that each match `matches` numbers
+fn deck(n: u32, matches: u32) -> Vec<Scratchcard> {
+    return (1..=n)
+        .map(|num| Scratchcard {
+            num,
+            winners: (1..=matches).collect(),
+            haves: (1..=matches).chain([99]).collect(),
+        })
+        .collect();
+}
+
+#[test]
+fn big_counts_print_in_decimal() {
+    let big = |n: u128| BigCount::from(n).to_string();
+    assert_eq!(big(0), "0");
+    assert_eq!(big(1_000_000_000), "1000000000");
+    assert_eq!(big(u128::MAX), u128::MAX.to_string());
+    assert_eq!(
+        BigCount::try_pow2(64).unwrap().to_string(),
+        "18446744073709551616"
+    );
+
+    let product = BigCount::from(u128::MAX)
+        .try_mul(&BigCount::from(u128::MAX))
+        .unwrap();
+    assert_eq!(
+        product.to_string(),
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+    );
+    assert_eq!(format!("{:>5}", BigCount::from(42)), "   42");
+    assert!(BigCount::from(1 << 32) > BigCount::from(u32::MAX as u128));
+    assert!(BigCount::from(2) < BigCount::from(3));
+}
+
+#[test]
+fn overflow_is_an_error_not_a_wrap() {
+    // Two matches per card make the copies grow like the Fibonacci numbers: the total is
+    // F(n + 4) - n - 3, and F(100) = 354224848179261915075
+    let cards = deck(96, 2);
+    assert_eq!(
+        total_copies::<u64>(&cards).unwrap_err(),
+        "Copies of card 92 overflow u64"
+    );
+    let expected = 354224848179261915075u128 - 96 - 3;
+    assert_eq!(total_copies::<u128>(&cards).unwrap(), expected);
+    assert_eq!(
+        total_copies::<BigCount>(&cards).unwrap(),
+        BigCount::from(expected)
+    );
+
+    let cards = deck(3000, 2);
+    assert!(total_copies::<u128>(&cards).is_err());
+    let total = total_copies::<BigCount>(&cards).unwrap().to_string();
+    assert_eq!(total.len(), 628);
+    assert!(total.starts_with("28144031537168227375"), "{}", total);
+    assert!(total.ends_with("6514283000"), "{}", total);
+
+    let cards = deck(1, 65);
+    assert_eq!(
+        points::<u64>(&cards).unwrap_err(),
+        "Points up to card 1 overflow u64"
+    );
+    assert_eq!(points::<u128>(&cards).unwrap(), 1 << 64);
+}
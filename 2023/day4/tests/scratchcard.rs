@@ -0,0 +1,44 @@
+use aoc_common::Solution;
+use day4::{copies, Day4, Scratchcard};
+
+const EXAMPLE: &str = include_str!("../src/test_input.txt");
+
+#[test]
+fn cards_parse_with_any_padding() {
+    let cards = Day4::parse("Card   12:1  2 3|  4 5 1\nCard 13: 7 | 8 9 10 7 7").unwrap();
+    assert_eq!(
+        cards[0],
+        Scratchcard {
+            num: 12,
+            winners: vec![1, 2, 3],
+            haves: vec![4, 5, 1],
+        }
+    );
+    assert_eq!(cards[0].matches(), 1);
+    assert_eq!(cards[1].matches(), 2);
+}
+
+#[test]
+fn malformed_cards_are_located() {
+    let error = |input: &str| Day4::parse(input).unwrap_err();
+
+    assert_eq!(error("Card 1 41 | 41").message, "Expected \":\"");
+    assert_eq!(
+        error("Cards 1: 41 | 41").message,
+        "Expected a space after \"Card\""
+    );
+    assert_eq!(error("Card 1: 41 41").message, "Expected \"|\"");
+    let e = error("Card 1: 41 | 41\nCard 2: 4x | 41");
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 9, "4x"));
+}
+
+#[test]
+fn copies_stop_at_the_last_card() {
+    let cards = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(copies::<u64>(&cards).unwrap(), vec![1, 2, 4, 8, 14, 1]);
+
+    // Every card matches more cards than are left
+    let cards = Day4::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 1 2 | 1 2").unwrap();
+    assert_eq!(copies::<u64>(&cards).unwrap(), vec![1, 2]);
+    assert_eq!(copies::<u64>(&[]).unwrap(), Vec::<u64>::new());
+}
@@ -0,0 +1,137 @@
+use std::process::ExitCode;
+
+use aoc_common::{Interval, RangeSet, Solution};
+use day5::{Almanac, Day5};
+
+const INPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+const USAGE: &str = "Usage: almanac route FROM TO [VALUE...] [--input INPUT]
+       almanac reverse LOCATION|START..END [--input INPUT]
+e.g. almanac route fertilizer location 14 53
+     almanac reverse 40..50";
+
+fn number(text: &str) -> Result<i64, String> {
+    return text
+        .parse()
+        .map_err(|_| format!("Expected a number, got \"{}\"", text));
+}
+
+/// A single value, or the half-open range `START..END`
+fn parse_range(text: &str) -> Result<Interval<i64>, String> {
+    let (start, end) = match text.split_once("..") {
+        Some((start, end)) => (number(start)?, number(end)?),
+        None => {
+            let value = number(text)?;
+            let end = value.checked_add(1).ok_or("That location is too large")?;
+            (value, end)
+        }
+    };
+    if end < start {
+        return Err(format!("The range {} is backwards", text));
+    }
+    return Ok(Interval::new(start, end));
+}
+
+fn show(set: &RangeSet<i64>) -> String {
+    if set.is_empty() {
+        return "none".to_string();
+    }
+    let intervals: Vec<String> = set
+        .intervals()
+        .map(|i| format!("{}..{}", i.start, i.end))
+        .collect();
+    return intervals.join(", ");
+}
+
+/// Prints the maps from `from` to `to` and where `values` end up. Without values, prints
+/// the composed map instead, and from seeds, the lowest value any seed reaches.
+fn route(almanac: &Almanac, from: &str, to: &str, values: &[i64]) -> Result<(), String> {
+    let route = almanac.route(from, to)?;
+    let mut categories = vec![from];
+    categories.extend(route.iter().map(|m| m.to.as_str()));
+    println!("Route: {}", categories.join(" -> "));
+
+    let map = almanac.map_between(from, to)?;
+    for &value in values {
+        println!("{} {} -> {} {}", from, value, to, map.get(value));
+    }
+    if !values.is_empty() {
+        return Ok(());
+    }
+
+    for piece in map.pieces() {
+        let dest = piece.dest_interval();
+        println!(
+            "{} {}..{} -> {} {}..{}",
+            from, piece.src.start, piece.src.end, to, dest.start, dest.end
+        );
+    }
+    if from == "seed" {
+        let lowest = |seeds: &RangeSet<i64>| map.image(seeds).min().ok_or("There are no seeds");
+        println!("Lowest {} for the seeds: {}", to, lowest(&almanac.seeds())?);
+        println!(
+            "Lowest {} for the seed ranges: {}",
+            to,
+            lowest(&almanac.seed_ranges()?)?
+        );
+    }
+    return Ok(());
+}
+
+/// Prints the seeds that end up at `locations`, and which of them the almanac lists
+fn reverse(almanac: &Almanac, locations: Interval<i64>) -> Result<(), String> {
+    let lookup = almanac.seeds_at(&RangeSet::new([locations]))?;
+    println!(
+        "Seeds at location {}..{}: {}",
+        locations.start,
+        locations.end,
+        show(&lookup.seeds)
+    );
+    println!("In the seed list: {}", show(&lookup.in_seed_list));
+    match lookup.in_seed_ranges {
+        Some(in_ranges) => println!("In the seed ranges: {}", show(&in_ranges)),
+        None => println!("In the seed ranges: the seeds aren't start and length pairs"),
+    }
+    return Ok(());
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut path = INPUT_FILE;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => path = args.next().ok_or(USAGE)?,
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let contents = aoc_common::load_input(path)?;
+    let almanac = Day5::parse(&contents).map_err(|e| e.with_file(path).to_string())?;
+
+    return match positional.as_slice() {
+        ["route", from, to, values @ ..] => {
+            let values = values
+                .iter()
+                .map(|v| number(v))
+                .collect::<Result<Vec<i64>, String>>()?;
+            route(&almanac, from, to, &values)
+        }
+        ["reverse", locations] => reverse(&almanac, parse_range(locations)?),
+        _ => Err(USAGE.to_string()),
+    };
+}
+
+/// Answers questions about the almanac's maps
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
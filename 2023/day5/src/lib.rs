@@ -0,0 +1,267 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+use aoc_common::{Interval, IntervalMap, Line, ParseError, Piece, RangeSet, Solution};
+
+/// Parses a `<dest start> <src start> <length>` rule
+fn parse_piece(line: &Line) -> Result<Piece<i64>, ParseError> {
+    let as_ints: Vec<i64> = line.parse_all(line.text)?;
+
+    if as_ints.len() != 3 {
+        return Err(line.error(line.text, "Expected \"<dest start> <src start> <length>\""));
+    }
+    if as_ints[2] < 0 {
+        return Err(line.error(line.text, "Length can't be negative"));
+    }
+
+    let too_large = || line.error(line.text, "Range goes past the largest number");
+    let src = Interval::checked_with_len(as_ints[1], as_ints[2]).ok_or_else(too_large)?;
+    Interval::checked_with_len(as_ints[0], as_ints[2]).ok_or_else(too_large)?;
+
+    return Ok(Piece {
+        src,
+        dest: as_ints[0],
+    });
+}
+
+/// One `<from>-to-<to> map:` section
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryMap {
+    pub from: String,
+    pub to: String,
+    pub map: IntervalMap<i64>,
+}
+
+fn parse_maps(lines: &[Line]) -> Result<Vec<CategoryMap>, ParseError> {
+    let mut output: Vec<CategoryMap> = Vec::new();
+
+    // Header line and categories of the map being read
+    let mut current: Option<(&Line, &str, &str)> = None;
+    let mut map_rules = Vec::<Piece<i64>>::new();
+    let mut finish = |header: &Line, from: &str, to: &str, rules: Vec<Piece<i64>>| {
+        if output.iter().any(|m| m.from == from && m.to == to) {
+            return Err(header.error(
+                header.text,
+                format!("There is already a {}-to-{} map", from, to),
+            ));
+        }
+        output.push(CategoryMap {
+            from: from.to_string(),
+            to: to.to_string(),
+            map: IntervalMap::new(rules).map_err(|e| header.error(header.text, e))?,
+        });
+        return Ok(());
+    };
+
+    for line in lines {
+        if line.is_empty() {
+            if let Some((header, from, to)) = current.take() {
+                finish(header, from, to, std::mem::take(&mut map_rules))?;
+            }
+        } else if line.contains(":") {
+            let (from, to) = line
+                .text
+                .strip_suffix(" map:")
+                .and_then(|name| name.split_once("-to-"))
+                .ok_or_else(|| line.error(line.text, "Expected \"<from>-to-<to> map:\""))?;
+            // A header straight after a map's rules still ends that map
+            if let Some((header, from, to)) = current.take() {
+                finish(header, from, to, std::mem::take(&mut map_rules))?;
+            }
+            current = Some((line, from, to));
+        } else if current.is_none() {
+            return Err(line.error(line.text, "Rule outside of a map"));
+        } else {
+            map_rules.push(parse_piece(line)?);
+        }
+    }
+    if let Some((header, from, to)) = current {
+        finish(header, from, to, map_rules)?;
+    }
+
+    return Ok(output);
+}
+
+pub struct Almanac {
+    seed_nums: Vec<i64>,
+    /// Maps in the order they appear
+    maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    pub fn seed_nums(&self) -> &[i64] {
+        return &self.seed_nums;
+    }
+
+    /// Every category a map goes from or to, in alphabetical order
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self
+            .maps
+            .iter()
+            .flat_map(|m| [m.from.as_str(), m.to.as_str()])
+            .collect();
+        categories.sort();
+        categories.dedup();
+        return categories;
+    }
+
+    /// The fewest maps that lead from `from` to `to`, in the order they apply
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap>, String> {
+        let categories = self.categories();
+        for category in [from, to] {
+            if !categories.contains(&category) {
+                return Err(format!("There is no \"{}\" category", category));
+            }
+        }
+
+        // Breadth-first, remembering the map that first reached each category
+        let mut reached_by: HashMap<&str, Option<&CategoryMap>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut route = Vec::new();
+                let mut at = to;
+                while let Some(map) = reached_by[at] {
+                    route.push(map);
+                    at = &map.from;
+                }
+                route.reverse();
+                return Ok(route);
+            }
+
+            for map in self.maps.iter().filter(|m| m.from == category) {
+                if !reached_by.contains_key(map.to.as_str()) {
+                    reached_by.insert(&map.to, Some(map));
+                    queue.push_back(&map.to);
+                }
+            }
+        }
+
+        return Err(format!("No maps lead from {} to {}", from, to));
+    }
+
+    /// The maps on the route from `from` to `to`, collapsed into one
+    pub fn map_between(&self, from: &str, to: &str) -> Result<IntervalMap<i64>, String> {
+        return Ok(self
+            .route(from, to)?
+            .iter()
+            .fold(IntervalMap::identity(), |reduced, m| {
+                reduced.compose(&m.map)
+            }));
+    }
+
+    /// Every `from` value that ends up in `values`, a set of `to` values
+    pub fn inverse_image(
+        &self,
+        from: &str,
+        to: &str,
+        values: &RangeSet<i64>,
+    ) -> Result<RangeSet<i64>, String> {
+        return Ok(self
+            .route(from, to)?
+            .iter()
+            .rev()
+            .fold(values.clone(), |values, m| m.map.inverse_image(&values)));
+    }
+
+    /// The seeds read as single seed numbers (part 1)
+    pub fn seeds(&self) -> RangeSet<i64> {
+        return self
+            .seed_nums
+            .iter()
+            .map(|&n| Interval::with_len(n, 1))
+            .collect();
+    }
+
+    /// The seeds read as pairs of start and length (part 2)
+    pub fn seed_ranges(&self) -> Result<RangeSet<i64>, String> {
+        let pairs = self.seed_nums.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err("Seed ranges need a start and a length each".to_string());
+        }
+        return pairs
+            .map(|c| {
+                if c[1] < 0 {
+                    return Err(format!("Seed range from {} has a negative length", c[0]));
+                }
+                return Interval::checked_with_len(c[0], c[1]).ok_or(format!(
+                    "Seed range from {} goes past the largest number",
+                    c[0]
+                ));
+            })
+            .collect();
+    }
+
+    /// The lowest location any of `seeds` ends up at
+    pub fn min_location(&self, seeds: &RangeSet<i64>) -> Result<i64, String> {
+        return self
+            .map_between("seed", "location")?
+            .image(seeds)
+            .min()
+            .ok_or("There are no seeds".to_string());
+    }
+
+    /// Every seed that ends up at one of `locations`, and which of them the almanac lists
+    pub fn seeds_at(&self, locations: &RangeSet<i64>) -> Result<SeedLookup, String> {
+        let seeds = self.inverse_image("seed", "location", locations)?;
+        return Ok(SeedLookup {
+            in_seed_list: seeds.intersection(&self.seeds()),
+            in_seed_ranges: self.seed_ranges().ok().map(|r| seeds.intersection(&r)),
+            seeds,
+        });
+    }
+}
+
+/// The seeds found by [`Almanac::seeds_at`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeedLookup {
+    pub seeds: RangeSet<i64>,
+    /// Those of `seeds` that are seed numbers (part 1)
+    pub in_seed_list: RangeSet<i64>,
+    /// Those of `seeds` in the seed ranges (part 2), unless the seeds can't be read as ranges
+    pub in_seed_ranges: Option<RangeSet<i64>>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Parse out seeds line
+        let lines = aoc_common::numbered_lines(input);
+        let seeds_line = &lines[0];
+        let mut seed_nums: Vec<i64> = Vec::new();
+        for token in seeds_line
+            .strip_prefix(seeds_line.text, "seeds: ")?
+            .split_whitespace()
+        {
+            let seed: i64 = seeds_line.parse(token)?;
+            // Each seed number is also read as the interval holding just that seed
+            if seed == i64::MAX {
+                return Err(seeds_line.error(token, "Seed number is too large"));
+            }
+            seed_nums.push(seed);
+        }
+
+        // A blank line separates the seeds from the maps
+        if let Some(line) = lines.get(1) {
+            if !line.text.trim().is_empty() {
+                return Err(line.error(line.text, "Expected a blank line after the seeds"));
+            }
+        }
+
+        // Parse out list of split maps
+        let maps = parse_maps(lines.get(2..).unwrap_or_default())?;
+
+        return Ok(Almanac { seed_nums, maps });
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return input.min_location(&input.seeds());
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return input.min_location(&input.seed_ranges()?);
+    }
+}
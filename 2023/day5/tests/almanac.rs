@@ -0,0 +1,55 @@
+use day5::Day5;
+
+/// Every map but the first leaves its values alone
+fn almanac(seeds: &str, seed_to_soil: &str) -> String {
+    let mut text = format!("seeds: {}\n\nseed-to-soil map:\n{}\n", seeds, seed_to_soil);
+    for name in [
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ] {
+        text += &format!("\n{} map:\n", name);
+    }
+    return text;
+}
+
+fn answers(input: &str) -> Vec<String> {
+    return aoc_common::solve::<Day5>(input, &[1, 2]).unwrap();
+}
+
+#[test]
+fn seeds_outside_every_rule_keep_their_number() {
+    // No seed touches the rule, so every seed is its own location
+    assert_eq!(
+        answers(&almanac("500 10 700 3", "0 100 50")),
+        vec!["3", "500"]
+    );
+}
+
+#[test]
+fn gaps_between_rules_are_mapped_as_they_are() {
+    // 20..30 is moved up to 1000.., 40..50 up to 2000.., leaving 30..40 alone
+    let rules = "1000 20 10\n2000 40 10";
+    assert_eq!(answers(&almanac("25 20", rules))[1], "30");
+    assert_eq!(answers(&almanac("25 45", rules))[0], "1005");
+}
+
+#[test]
+fn bad_seed_ranges_are_errors() {
+    let solve = |seeds: &str| aoc_common::solve::<Day5>(&almanac(seeds, ""), &[2]);
+    assert!(solve("1 2 3").is_err());
+    assert!(solve("1 -2").is_err());
+}
+
+#[test]
+fn numbers_that_overflow_are_errors() {
+    let solve =
+        |seeds: &str, rules: &str| aoc_common::solve::<Day5>(&almanac(seeds, rules), &[1, 2]);
+    assert!(solve("1 2", "9223372036854775807 0 10").is_err());
+    assert!(solve("1 2", "0 9223372036854775807 10").is_err());
+    assert!(solve("9223372036854775807 0", "").is_err());
+    assert!(aoc_common::solve::<Day5>(&almanac("1 9223372036854775807", ""), &[2]).is_err());
+}
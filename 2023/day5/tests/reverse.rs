@@ -0,0 +1,59 @@
+use aoc_common::{Interval, RangeSet, Solution};
+use day5::Day5;
+
+const TEST_INPUT: &str = include_str!("../src/test_input.txt");
+
+fn set(intervals: &[(i64, i64)]) -> RangeSet<i64> {
+    return intervals
+        .iter()
+        .map(|&(start, end)| Interval::new(start, end))
+        .collect();
+}
+
+#[test]
+fn the_lowest_locations_lead_back_to_their_seeds() {
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+
+    let lookup = almanac.seeds_at(&set(&[(35, 36)])).unwrap();
+    assert_eq!(lookup.seeds, set(&[(13, 14)]));
+    assert_eq!(lookup.in_seed_list, set(&[(13, 14)]));
+    assert_eq!(lookup.in_seed_ranges, Some(RangeSet::empty()));
+
+    let lookup = almanac.seeds_at(&set(&[(46, 47)])).unwrap();
+    assert_eq!(lookup.seeds, set(&[(82, 83)]));
+    assert!(lookup.in_seed_list.is_empty());
+    assert_eq!(lookup.in_seed_ranges, Some(set(&[(82, 83)])));
+}
+
+#[test]
+fn reverse_lookups_are_exact() {
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+    let map = almanac.map_between("seed", "location").unwrap();
+    let locations = set(&[(0, 60), (90, 95)]);
+    let seeds = almanac.seeds_at(&locations).unwrap().seeds;
+    assert!((-50..200).all(|x| seeds.contains(x) == locations.contains(map.get(x))));
+}
+
+#[test]
+fn any_two_categories_can_be_reversed() {
+    // Soil 81 comes from seed 79, soil 50..52 only from 98..100, and location 43 from
+    // fertilizer 53
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+    let inverse = |from, to, values| almanac.inverse_image(from, to, &values).unwrap();
+    assert_eq!(inverse("seed", "soil", set(&[(81, 82)])), set(&[(79, 80)]));
+    assert_eq!(inverse("seed", "soil", set(&[(50, 52)])), set(&[(98, 100)]));
+    assert!(inverse("fertilizer", "location", set(&[(43, 44)])).contains(53));
+    assert!(almanac
+        .inverse_image("location", "seed", &set(&[(0, 1)]))
+        .is_err());
+}
+
+#[test]
+fn seeds_that_are_not_ranges_are_still_looked_up() {
+    let almanac = Day5::parse("seeds: 1 2 3\n\nseed-to-location map:\n10 0 5\n").unwrap();
+    let lookup = almanac.seeds_at(&set(&[(11, 13)])).unwrap();
+    // 11 and 12 are left alone as well
+    assert_eq!(lookup.seeds, set(&[(1, 3), (11, 13)]));
+    assert_eq!(lookup.in_seed_list, set(&[(1, 3)]));
+    assert_eq!(lookup.in_seed_ranges, None);
+}
@@ -0,0 +1,97 @@
+use aoc_common::Solution;
+use day5::{Almanac, Day5};
+
+const TEST_INPUT: &str = include_str!("../src/test_input.txt");
+
+fn route(almanac: &Almanac, from: &str, to: &str) -> Result<Vec<String>, String> {
+    return Ok(almanac
+        .route(from, to)?
+        .iter()
+        .map(|m| format!("{}-to-{}", m.from, m.to))
+        .collect());
+}
+
+#[test]
+fn the_example_chain_is_found_from_its_headers() {
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+    assert_eq!(
+        route(&almanac, "fertilizer", "light").unwrap(),
+        vec!["fertilizer-to-water", "water-to-light"]
+    );
+    assert_eq!(
+        route(&almanac, "seed", "seed").unwrap(),
+        Vec::<String>::new()
+    );
+    assert_eq!(route(&almanac, "seed", "location").unwrap().len(), 7);
+}
+
+#[test]
+fn only_the_maps_on_the_route_are_composed() {
+    // The puzzle's walk-through for seed 14
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+    let from_fertilizer = almanac.map_between("fertilizer", "location").unwrap();
+    assert_eq!(from_fertilizer.get(53), 43);
+    assert_eq!(almanac.map_between("seed", "humidity").unwrap().get(14), 43);
+    assert_eq!(almanac.map_between("water", "water").unwrap().get(5), 5);
+}
+
+#[test]
+fn maps_can_branch_and_come_in_any_order() {
+    let input = "seeds: 1
+
+b-to-c map:
+100 0 10
+
+a-to-b map:
+10 0 10
+
+a-to-c map:
+50 0 10
+
+c-to-d map:
+0 100 10
+";
+    let almanac = Day5::parse(input).unwrap();
+    assert_eq!(almanac.categories(), vec!["a", "b", "c", "d"]);
+    // The direct map beats going through b
+    assert_eq!(route(&almanac, "a", "d").unwrap(), vec!["a-to-c", "c-to-d"]);
+    assert_eq!(almanac.map_between("a", "d").unwrap().get(1), 51);
+    assert_eq!(almanac.map_between("b", "d").unwrap().get(1), 1);
+}
+
+#[test]
+fn missing_routes_are_errors() {
+    let almanac = Day5::parse(TEST_INPUT).unwrap();
+    assert!(almanac.route("location", "seed").is_err());
+    assert!(almanac.route("seed", "weather").is_err());
+    // Without a humidity-to-location map there is no way to a location
+    let input = TEST_INPUT.split("humidity-to-location").next().unwrap();
+    assert!(aoc_common::solve::<Day5>(input, &[1]).is_err());
+}
+
+#[test]
+fn bad_headers_are_errors() {
+    assert!(Day5::parse("seeds: 1\n\nseed-soil map:\n1 2 3\n").is_err());
+    assert!(Day5::parse("seeds: 1\n\na-to-b map:\n\na-to-b map:\n").is_err());
+}
+
+#[test]
+fn the_seeds_need_a_blank_line_after_them() {
+    let Err(error) = Day5::parse("seeds: 1\nseed-to-soil map:\n100 0 10\n") else {
+        panic!("Parsed maps with no blank line before them");
+    };
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.token, "seed-to-soil map:");
+    assert!(Day5::parse("seeds: 1\n  \nseed-to-soil map:\n100 0 10\n").is_ok());
+}
+
+#[test]
+fn maps_need_no_blank_line_between_them() {
+    let input = "seeds: 1\n\nseed-to-soil map:\n100 0 10\nsoil-to-location map:\n0 100 5\n";
+    let almanac = Day5::parse(input).unwrap();
+    assert_eq!(
+        route(&almanac, "seed", "location").unwrap(),
+        vec!["seed-to-soil", "soil-to-location"]
+    );
+    assert_eq!(almanac.map_between("seed", "location").unwrap().get(1), 1);
+}
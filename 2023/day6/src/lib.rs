@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use aoc_common::{Line, ParseError, Solution};
+
+fn num_ways_to_win(time: u64, distance_record: u64) -> u64 {
+    let time_f = time as f64;
+    let distance_record_f = distance_record as f64;
+
+    let discriminant = time_f.powf(2.0) - 4.0 * distance_record_f;
+    if discriminant < 0.0 {
+        return 0;
+    }
+
+    // Only strictly beating the record counts, so a hold time that exactly ties it is excluded
+    let lower_bound = (0.5 * (time_f - discriminant.sqrt())).floor() as u64 + 1;
+    let upper_bound_exclusive = (0.5 * (time_f + discriminant.sqrt())).ceil() as u64;
+
+    // A race with no winning hold time has the bounds cross
+    return upper_bound_exclusive.saturating_sub(lower_bound);
+}
+
+// Brute-force equivalent of num_ways_to_win, kept for comparison
+fn num_ways_to_win_2(time: u64, distance_record: u64) -> usize {
+    let beats_record: Vec<bool> = (0..time)
+        .map(|bt| bt * (time - bt))
+        .map(|d| d > distance_record)
+        .collect();
+
+    let Some(lower_bound) = beats_record.iter().position(|&x| x) else {
+        return 0;
+    };
+    let upper_bound = beats_record.iter().rposition(|&x| x).unwrap_or(lower_bound);
+
+    return upper_bound - lower_bound + 1;
+}
+
+/// Race sheet, one entry per column of the input
+pub struct Races {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+fn parse_row(line: &Line, label: &str) -> Result<Vec<String>, ParseError> {
+    let values = line.strip_prefix(line.text, label)?;
+
+    // Validate now, but keep the digits so part 2 can join them
+    line.parse_all::<u64>(values)?;
+    return Ok(values
+        .split_whitespace()
+        .map(String::from)
+        .collect());
+}
+
+/// Product of the number of ways to win each race
+fn margin_of_error(races: &Races, num_ways: fn(u64, u64) -> u64) -> u64 {
+    return races
+        .times
+        .iter()
+        .zip(races.distances.iter())
+        .map(|(t, d)| num_ways(t.parse().unwrap(), d.parse().unwrap()))
+        .product::<u64>();
+}
+
+/// Number of ways to win the one long race
+fn kerned_race(races: &Races, num_ways: fn(u64, u64) -> u64) -> Result<u64, String> {
+    // The sheet has bad kerning: all columns form a single race
+    let time: u64 = races.times.concat().parse().map_err(|e| format!("Race time: {}", e))?;
+    let distance: u64 = races
+        .distances
+        .concat()
+        .parse()
+        .map_err(|e| format!("Race distance: {}", e))?;
+
+    return Ok(num_ways(time, distance));
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = aoc_common::numbered_lines(input);
+        let distance_line = lines
+            .get(1)
+            .ok_or_else(|| ParseError::at_end(input, "Missing the Distance line"))?;
+
+        let times = parse_row(&lines[0], "Time:")?;
+        let distances = parse_row(distance_line, "Distance:")?;
+        if times.len() != distances.len() {
+            let message = "Expected one distance per race time";
+            return Err(distance_line.error(distance_line.text, message));
+        }
+
+        return Ok(Races { times, distances });
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(margin_of_error(input, num_ways_to_win));
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return kerned_race(input, num_ways_to_win);
+    }
+}
+
+/// Day 6 solved by trying every button hold time, to compare against the closed form
+pub struct Day6BruteForce;
+
+impl Solution for Day6BruteForce {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Day6::parse(input);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(margin_of_error(input, |t, d| num_ways_to_win_2(t, d) as u64));
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return kerned_race(input, |t, d| num_ways_to_win_2(t, d) as u64);
+    }
+}
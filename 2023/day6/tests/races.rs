@@ -0,0 +1,24 @@
+use day6::{Day6, Day6BruteForce};
+
+/// The answers, checked to be the same by the closed form and by brute force
+fn answers(input: &str) -> Vec<String> {
+    let answers = aoc_common::solve::<Day6>(input, &[1, 2]).unwrap();
+    assert_eq!(
+        aoc_common::solve::<Day6BruteForce>(input, &[1, 2]).unwrap(),
+        answers
+    );
+    return answers;
+}
+
+#[test]
+fn races_nobody_can_win_have_no_ways() {
+    assert_eq!(answers("Time: 0\nDistance: 0\n"), vec!["0", "0"]);
+    assert_eq!(answers("Time: 3\nDistance: 5\n"), vec!["0", "0"]);
+    // Holding for 2 of 4 only ties the record
+    assert_eq!(answers("Time: 4\nDistance: 4\n"), vec!["0", "0"]);
+}
+
+#[test]
+fn ties_with_the_record_are_not_wins() {
+    assert_eq!(answers("Time: 30\nDistance: 200\n"), vec!["9", "9"]);
+}
@@ -0,0 +1,188 @@
+use counter::Counter;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+/// Card order when J is a Jack
+const CARD_STRENGTH_NO_JOKERS: &str = "23456789TJQKA";
+/// Card order when J is a Joker
+const CARD_STRENGTH: &str = "J23456789TQKA";
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum HandType {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPair = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Hand {
+    handtype: HandType,
+    cards: String,
+    best_hand: String,
+    jokers: bool,
+}
+
+impl Hand {
+    pub fn new(cards: &String, jokers: bool) -> Self {
+        let best_hand = if jokers {
+            replace_jokers(cards)
+        } else {
+            String::from(cards)
+        };
+        let char_counts = best_hand.chars().collect::<Counter<_>>();
+        let count_counts = char_counts.values().collect::<Counter<_>>();
+
+        let handtype = match (
+            count_counts.get(&2),
+            count_counts.get(&3),
+            count_counts.get(&4),
+            count_counts.get(&5),
+        ) {
+            (Some(_), Some(_), _, _) => HandType::FullHouse,
+            (_, _, _, Some(_)) => HandType::FiveOfAKind,
+            (_, _, Some(_), _) => HandType::FourOfAKind,
+            (_, Some(_), _, _) => HandType::ThreeOfAKind,
+            (Some(&2), _, _, _) => HandType::TwoPair,
+            (Some(&1), _, _, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        };
+
+        return Self {
+            handtype,
+            cards: String::from(cards),
+            best_hand,
+            jokers,
+        };
+    }
+
+    fn card_strength(&self) -> &'static str {
+        if self.jokers {
+            return CARD_STRENGTH;
+        }
+        return CARD_STRENGTH_NO_JOKERS;
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_key = (
+            &self.handtype,
+            self.cards
+                .chars()
+                .map(|c| self.card_strength().find(c).expect("Invalid card character"))
+                .collect::<Vec<_>>(),
+        );
+
+        let other_key = (
+            &other.handtype,
+            other
+                .cards
+                .chars()
+                .map(|c| other.card_strength().find(c).expect("Invalid card character"))
+                .collect::<Vec<_>>(),
+        );
+
+        return self_key.cmp(&other_key);
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+fn replace_jokers(cards: &String) -> String {
+    let num_jokers = cards.chars().filter(|c| *c == 'J').count();
+    if num_jokers == 0 {
+        return String::from(cards);
+    }
+    if num_jokers == 5 {
+        return String::from("AAAAA");
+    }
+
+    let char_counts = cards
+        .chars()
+        .filter(|c| *c != 'J')
+        .collect::<Counter<char>>();
+    let highest_count = char_counts
+        .most_common()
+        .first()
+        .expect("Error: no char counts")
+        .1;
+
+    let highest_count_chars: Vec<char> = char_counts
+        .iter()
+        .filter(|count| *count.1 == highest_count)
+        .map(|x| *x.0)
+        .collect();
+
+    let replace_joker_with_index = highest_count_chars
+        .iter()
+        .map(|c| CARD_STRENGTH.find(*c).expect("Invalid card character"))
+        .max()
+        .expect("Error getting highest count char");
+    let replace_joker_with = CARD_STRENGTH
+        .chars()
+        .nth(replace_joker_with_index)
+        .expect("Error getting nth char");
+
+    let result = cards.replace("J", replace_joker_with.to_string().as_str());
+
+    return result;
+}
+
+/// Total winnings: each hand's bid multiplied by its rank
+fn total_winnings(bids: &[(String, usize)], jokers: bool) -> usize {
+    let mut hands = bids
+        .iter()
+        .map(|(cards, bid)| (Hand::new(cards, jokers), *bid))
+        .collect::<Vec<_>>();
+    hands.sort();
+
+    return hands
+        .iter()
+        .enumerate()
+        .fold(0, |sum, (rank, (_hand, bid))| sum + (rank + 1) * bid);
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    /// (cards, bid) for each hand
+    type Input = Vec<(String, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut hands: Vec<(String, usize)> = Vec::new();
+
+        for line in aoc_common::numbered_lines(input) {
+            let (cards, bid) = line.split_once(line.text, " ")?;
+
+            if cards.chars().count() != 5 {
+                return Err(line.error(cards, "A hand has exactly 5 cards"));
+            }
+            let invalid = cards.char_indices().find(|(_, c)| !CARD_STRENGTH.contains(*c));
+            if let Some((i, c)) = invalid {
+                return Err(line.error(&cards[i..i + c.len_utf8()], "Invalid card character"));
+            }
+
+            hands.push((cards.to_string(), line.parse::<usize>(bid)?));
+        }
+
+        return Ok(hands);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(total_winnings(input, false));
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(total_winnings(input, true));
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc_common::{Line, ParseError, Solution};
+
+fn parse_line<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    // Takes a string of the form "AAA = (BBB, CCC)"
+    let (node, branches) = line.split_once(line.text, " = (")?;
+    let branches = branches
+        .strip_suffix(")")
+        .ok_or_else(|| line.error(branches, "Expected \")\""))?;
+    let (left, right) = line.split_once(branches, ", ")?;
+
+    return Ok((node, left, right));
+}
+
+fn step<'a>(current: &'a str, dir: char, map: &'a HashMap<String, (String, String)>) -> &'a str {
+    if dir == 'L' {
+        return &map[current].0;
+    }
+    return &map[current].1;
+}
+
+fn compute_path_to_z<'a>(
+    start_node: &'a str,
+    sequence: &[char],
+    map: &'a HashMap<String, (String, String)>,
+) -> Result<Vec<&'a str>, String> {
+    let mut sequence_index: usize = 0;
+    let mut current_node: &str = start_node;
+    let mut path: Vec<&str> = vec![{ current_node }];
+    // Being back at a node at the same point in the sequence means walking in a loop
+    let mut seen: HashSet<(&str, usize)> = HashSet::new();
+    while !current_node.ends_with('Z') {
+        if !seen.insert((current_node, sequence_index)) {
+            return Err(format!("Walking from {} never reaches a node ending in Z", start_node));
+        }
+        let dir = sequence[sequence_index];
+        current_node = step(current_node, dir, map);
+        path.push(current_node);
+        sequence_index = (sequence_index + 1) % sequence.len();
+    }
+
+    return Ok(path);
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        return a;
+    }
+    return gcd(b, a % b);
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    return a / gcd(a, b) * b;
+}
+
+pub struct Network {
+    sequence: Vec<char>,
+    map: HashMap<String, (String, String)>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = aoc_common::numbered_lines(input);
+
+        let sequence_line = &lines[0];
+        if sequence_line.is_empty() {
+            return Err(sequence_line.error(sequence_line.text, "Expected a sequence of L and R"));
+        }
+        let invalid = sequence_line.char_indices().find(|(_, c)| *c != 'L' && *c != 'R');
+        if let Some((i, c)) = invalid {
+            let dir = &sequence_line.text[i..i + c.len_utf8()];
+            return Err(sequence_line.error(dir, "Directions must be L or R"));
+        }
+        let sequence: Vec<char> = sequence_line.chars().collect();
+
+        let node_lines = lines.get(2..).unwrap_or_default();
+        let mut parsed = Vec::new();
+        for line in node_lines {
+            parsed.push((line, parse_line(line)?));
+        }
+
+        let mut map: HashMap<String, (String, String)> = HashMap::new();
+        for (_, (node, left, right)) in &parsed {
+            map.insert(node.to_string(), (left.to_string(), right.to_string()));
+        }
+
+        // Every branch must lead somewhere, so walking the network can only fail by looping
+        for (line, (_, left, right)) in &parsed {
+            for branch in [left, right] {
+                if !map.contains_key(*branch) {
+                    return Err(line.error(branch, format!("Unknown node \"{}\"", branch)));
+                }
+            }
+        }
+
+        return Ok(Network { sequence, map });
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        if !input.map.contains_key("AAA") {
+            return Err("There is no node AAA to start from".to_string());
+        }
+
+        return Ok(compute_path_to_z("AAA", &input.sequence, &input.map)?.len() - 1);
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        let mut start_nodes: Vec<&str> = input
+            .map
+            .keys()
+            .filter(|n| n.ends_with('A'))
+            .map(|n| n.as_str())
+            .collect();
+        start_nodes.sort();
+
+        let lengths: Vec<u64> = start_nodes
+            .iter()
+            .map(|sn| Ok((compute_path_to_z(sn, &input.sequence, &input.map)?.len() - 1) as u64))
+            .collect::<Result<_, String>>()?;
+
+        return Ok(lengths.into_iter().fold(1, lcm));
+    }
+}
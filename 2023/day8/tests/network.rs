@@ -0,0 +1,19 @@
+use day8::Day8;
+
+#[test]
+fn networks_that_never_reach_z_are_errors() {
+    let input = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n";
+    let err = aoc_common::solve::<Day8>(input, &[1]).unwrap_err();
+    assert!(err.to_string().contains("never reaches"), "{}", err);
+    assert!(aoc_common::solve::<Day8>(input, &[2]).is_err());
+}
+
+#[test]
+fn walks_can_pass_through_a_node_more_than_once() {
+    // AAA is visited again, but at a different point in the sequence
+    let input = "LLR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(
+        aoc_common::solve::<Day8>(input, &[1, 2]).unwrap(),
+        vec!["3", "3"]
+    );
+}
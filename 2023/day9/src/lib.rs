@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+fn derive(input: &[i64]) -> Vec<i64> {
+    let mut out: Vec<i64> = Vec::new();
+    for (i, n) in input.iter().skip(1).enumerate() {
+        out.push(*n - input[i]);
+    }
+
+    return out;
+}
+
+fn predict(input: &[i64]) -> i64 {
+    assert!(!input.is_empty());
+
+    // A single value has no differences, and is predicted to stay as it is
+    let dxdt = derive(input);
+    if dxdt.iter().all(|&x| x == 0i64) {
+        return input.iter().last().expect("") + dxdt.iter().last().unwrap_or(&0);
+    }
+
+    let dx = predict(&dxdt);
+    return input.iter().last().expect("") + dx;
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut histories: Vec<Vec<i64>> = Vec::new();
+
+        for line in aoc_common::numbered_lines(input) {
+            let history: Vec<i64> = line.parse_all(line.text)?;
+            if history.is_empty() {
+                return Err(line.error(line.text, "Expected a history of values"));
+            }
+            histories.push(history);
+        }
+
+        return Ok(histories);
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(input.iter().map(|line| predict(line)).sum::<i64>());
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        return Ok(input
+            .iter()
+            .map(|line| predict(&line.iter().rev().copied().collect::<Vec<i64>>()))
+            .sum::<i64>());
+    }
+}
@@ -0,0 +1,17 @@
+use day9::Day9;
+
+fn answers(input: &str) -> Vec<String> {
+    return aoc_common::solve::<Day9>(input, &[1, 2]).unwrap();
+}
+
+#[test]
+fn a_single_value_predicts_itself() {
+    assert_eq!(answers("5\n"), vec!["5", "5"]);
+    assert_eq!(answers("5\n1 2 3\n"), vec!["9", "5"]);
+}
+
+#[test]
+fn histories_that_never_settle_still_predict() {
+    // The differences run out before reaching all zeros
+    assert_eq!(answers("1 2 4\n"), vec!["7", "1"]);
+}
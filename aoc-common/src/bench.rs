@@ -0,0 +1,70 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Error, Solution};
+
+/// Wall-clock time of each phase of a solution, one sample per iteration
+#[derive(Default)]
+pub struct PhaseSamples {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+/// Times parsing and each part of `S` separately, `iterations` times over.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<PhaseSamples, Error> {
+    let mut samples = PhaseSamples::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        samples.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed).map_err(Error::Solve)?);
+        samples.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed).map_err(Error::Solve)?);
+        samples.part2.push(start.elapsed());
+    }
+
+    return Ok(samples);
+}
+
+/// Summary statistics of a set of timing samples, in nanoseconds
+#[derive(Clone, Copy, Debug)]
+pub struct Summary {
+    pub median: f64,
+    pub p95: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    pub min: f64,
+}
+
+impl Summary {
+    pub fn from_samples(samples: &[Duration]) -> Summary {
+        assert!(!samples.is_empty());
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        return Summary {
+            median: percentile(&nanos, 0.5),
+            p95: percentile(&nanos, 0.95),
+            mean,
+            stddev: variance.sqrt(),
+            min: nanos[0],
+        };
+    }
+}
+
+/// Nearest-rank percentile of already sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    return sorted[rank.clamp(1, sorted.len()) - 1];
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+/// Malformed puzzle input, located at the offending token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Input file, when known. Parsers only see the contents, so the caller fills this in.
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+    /// The full text of the offending line, for the diagnostic
+    source_line: String,
+}
+
+impl ParseError {
+    /// Error at the 0-based `column` (in characters) of the 0-based `line_index`.
+    pub fn new(
+        source_line: &str,
+        line_index: usize,
+        column: usize,
+        token: &str,
+        message: impl Into<String>,
+    ) -> ParseError {
+        return ParseError {
+            file: None,
+            line: line_index + 1,
+            column: column + 1,
+            token: token.to_string(),
+            message: message.into(),
+            source_line: source_line.to_string(),
+        };
+    }
+
+    /// Error for something missing from the end of the input, e.g. a section that never appears.
+    pub fn at_end(input: &str, message: impl Into<String>) -> ParseError {
+        let lines = crate::lines(input);
+        let last = lines.last().copied().unwrap_or("");
+        return ParseError::new(last, lines.len() - 1, last.chars().count(), "", message);
+    }
+
+    /// Records the input file. `-` is reported as stdin, matching `load_input`.
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(match file {
+            "-" => "<stdin>".to_string(),
+            _ => file.to_string(),
+        });
+        return self;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let carets = "^".repeat(self.token.chars().count().max(1));
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), carets)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Anything that can go wrong while solving a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input could not be read
+    Input(String),
+    Parse(ParseError),
+    /// The input parsed but a part could not be answered from it
+    Solve(String),
+}
+
+impl Error {
+    /// Attaches the input file name to a parse error.
+    pub fn with_file(self, file: &str) -> Error {
+        match self {
+            Error::Parse(e) => Error::Parse(e.with_file(file)),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Solve(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        return Error::Parse(e);
+    }
+}
@@ -0,0 +1,195 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::ParseError;
+
+/// `(row, col)`, rows counted from the top
+pub type Position = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours, clockwise from north
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all eight neighbours, clockwise from north
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A dense rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Expected {}x{} cells",
+            width,
+            height
+        );
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    /// Parses a rectangular puzzle input, turning each character into a cell with `cell`.
+    /// Errors from `cell`, and rows of the wrong length, are located at the offending character.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let lines = crate::numbered_lines(input);
+        let width = lines[0].chars().count();
+        if width == 0 {
+            return Err(ParseError::at_end(input, "Expected a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            let mut count = 0;
+            for (col, (offset, c)) in line.char_indices().enumerate() {
+                if col == width {
+                    let extra = &line.text[offset..];
+                    let message = format!("Expected rows of {} cells", width);
+                    return Err(ParseError::new(line.text, line.index, col, extra, message));
+                }
+
+                let value = cell(c).map_err(|message| {
+                    let token = &line.text[offset..offset + c.len_utf8()];
+                    return ParseError::new(line.text, line.index, col, token, message);
+                })?;
+                cells.push(value);
+                count += 1;
+            }
+
+            if count < width {
+                let message = format!("Expected rows of {} cells", width);
+                return Err(ParseError::new(line.text, line.index, count, "", message));
+            }
+        }
+
+        return Ok(Grid::new(width, lines.len(), cells));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        return pos.0 < self.height && pos.1 < self.width;
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return Some(&self.cells[pos.0 * self.width + pos.1]);
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return Some(&mut self.cells[pos.0 * self.width + pos.1]);
+    }
+
+    /// The position `delta` away from `pos`, if it is inside the grid
+    pub fn offset(&self, pos: Position, delta: (isize, isize)) -> Option<Position> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        return self.contains((row, col)).then_some((row, col));
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        return (0..self.height).flat_map(move |row| (0..self.width).map(move |col| (row, col)));
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are inside the grid
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        return ORTHOGONAL.iter().filter_map(move |&d| self.offset(pos, d));
+    }
+
+    /// The up to eight neighbours of `pos`, diagonals included, that are inside the grid
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        return SURROUNDING.iter().filter_map(move |&d| self.offset(pos, d));
+    }
+
+    /// One row, or `None` past the bottom
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.height {
+            return None;
+        }
+        return Some(&self.cells[row * self.width..(row + 1) * self.width]);
+    }
+
+    /// One column from top to bottom, empty past the right edge
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        let height = if col < self.width { self.height } else { 0 };
+        return (0..height).map(move |row| &self.cells[row * self.width + col]);
+    }
+
+    /// The cells in `rows` x `cols` with their positions, row by row. The ranges are
+    /// clipped to the grid.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let rows = rows.start.min(self.height)..rows.end.min(self.height);
+        let cols = cols.start.min(self.width)..cols.end.min(self.width);
+        return rows.flat_map(move |row| {
+            cols.clone()
+                .map(move |col| ((row, col), &self.cells[row * self.width + col]))
+        });
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid::new(self.width, self.height, self.cells.iter().map(f).collect());
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        return self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        });
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self
+            .get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height));
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Reads puzzle input from the file at `path`, or from stdin when `path` is `-`.
+pub fn load_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| format!("Unable to read stdin: {}", e))?;
+        return Ok(contents);
+    }
+
+    return fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path, e));
+}
+
+/// Splits puzzle input into its lines. A trailing newline does not produce
+/// an extra empty line.
+pub fn lines(contents: &str) -> Vec<&str> {
+    return contents
+        .strip_suffix("\n")
+        .unwrap_or(contents)
+        .split("\n")
+        .collect();
+}
+
+/// Splits puzzle input into its lines, keeping each line's position so that
+/// parse errors can point at it.
+pub fn numbered_lines(contents: &str) -> Vec<Line<'_>> {
+    return lines(contents)
+        .into_iter()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+        .collect();
+}
+
+/// One line of puzzle input. Derefs to the line's text.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// 0-based line index
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.text
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at `token`, which should be a slice of this line.
+    /// Tokens from elsewhere are reported at the start of the line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match self.text.get(..offset) {
+            Some(before) if offset + token.len() <= self.text.len() => before.chars().count(),
+            _ => 0,
+        };
+
+        return ParseError::new(self.text, self.index, column, token, message);
+    }
+
+    /// Parses `token`, a slice of this line, reporting failures at its position.
+    pub fn parse<T>(&self, token: &'a str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        return token
+            .parse::<T>()
+            .map_err(|e| self.error(token, format!("Could not parse \"{}\": {}", token, e)));
+    }
+
+    /// Like `str::split_once`, but a missing separator is an error at `s`.
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        return s
+            .split_once(separator)
+            .ok_or_else(|| self.error(s, format!("Expected \"{}\"", separator)));
+    }
+
+    /// Like `str::strip_prefix`, but a missing prefix is an error at `s`.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        return s
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("Expected \"{}\"", prefix)));
+    }
+
+    /// Parses every whitespace-separated token of `s`, a slice of this line.
+    pub fn parse_all<T>(&self, s: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        return s
+            .split_whitespace()
+            .map(|token| self.parse(token))
+            .collect();
+    }
+}
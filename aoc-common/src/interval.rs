@@ -0,0 +1,377 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+/// The primitive integer types, which intervals and interval maps are generic over
+pub trait Integer: Copy + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> {
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn checked_add(self, other: $t) -> Option<$t> {
+                return <$t>::checked_add(self, other);
+            }
+        })*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The half-open interval `[start, end)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// Panics if `end` is before `start`
+    pub fn new(start: T, end: T) -> Interval<T> {
+        assert!(
+            start <= end,
+            "Interval from {:?} to {:?} is backwards",
+            start,
+            end
+        );
+        return Interval { start, end };
+    }
+
+    /// Panics if `start + len` overflows; see `checked_with_len`
+    pub fn with_len(start: T, len: T) -> Interval<T> {
+        return Interval::new(start, start + len);
+    }
+
+    /// The interval of `len` values from `start`, or `None` if its end doesn't fit in `T`
+    /// or `len` is negative
+    pub fn checked_with_len(start: T, len: T) -> Option<Interval<T>> {
+        let end = start.checked_add(len)?;
+        return (start <= end).then_some(Interval { start, end });
+    }
+
+    pub fn len(&self) -> T {
+        return self.end - self.start;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.start == self.end;
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        return self.start <= x && x < self.end;
+    }
+
+    /// The values in both intervals, if there are any
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        return (start < end).then_some(Interval { start, end });
+    }
+}
+
+/// Maps `src` onto the interval of the same length starting at `dest`. Lookups panic on
+/// overflow unless that interval fits in `T`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Piece<T> {
+    pub src: Interval<T>,
+    pub dest: T,
+}
+
+impl<T: Integer> Piece<T> {
+    /// The values `src` is mapped onto
+    pub fn dest_interval(&self) -> Interval<T> {
+        return Interval::with_len(self.dest, self.src.len());
+    }
+
+    /// Where `x`, which must be in `src`, is mapped to
+    pub fn get(&self, x: T) -> T {
+        debug_assert!(self.src.contains(x));
+        return self.dest + (x - self.src.start);
+    }
+
+    /// Where `y`, which must be in `dest_interval()`, is mapped from
+    pub fn inverse(&self, y: T) -> T {
+        debug_assert!(self.dest_interval().contains(y));
+        return self.src.start + (y - self.dest);
+    }
+
+    /// The same piece, restricted to the part of `src` in `within`
+    pub fn restrict(&self, within: Interval<T>) -> Option<Piece<T>> {
+        let src = self.src.intersection(&within)?;
+        return Some(Piece {
+            src,
+            dest: self.get(src.start),
+        });
+    }
+
+    fn is_identity(&self) -> bool {
+        return self.dest == self.src.start;
+    }
+}
+
+/// A function on integers made of pieces that each shift an interval, and that leaves
+/// every value outside the pieces as it is.
+///
+/// Pieces are kept sorted by source, without identity pieces, and with neighbouring pieces
+/// that shift by the same amount merged, so equal maps have equal pieces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalMap<T> {
+    pieces: Vec<Piece<T>>,
+}
+
+impl<T: Integer> IntervalMap<T> {
+    pub fn identity() -> IntervalMap<T> {
+        return IntervalMap { pieces: Vec::new() };
+    }
+
+    /// Fails if the sources of two pieces overlap
+    pub fn new(pieces: impl IntoIterator<Item = Piece<T>>) -> Result<IntervalMap<T>, String> {
+        let mut pieces: Vec<Piece<T>> = pieces.into_iter().filter(|p| !p.src.is_empty()).collect();
+        pieces.sort_by_key(|p| p.src.start);
+
+        for pair in pieces.windows(2) {
+            if pair[0].src.end > pair[1].src.start {
+                return Err(format!(
+                    "Pieces from {:?} and {:?} overlap",
+                    pair[0].src, pair[1].src
+                ));
+            }
+        }
+
+        let mut normalised: Vec<Piece<T>> = Vec::with_capacity(pieces.len());
+        for piece in pieces.into_iter().filter(|p| !p.is_identity()) {
+            match normalised.last_mut() {
+                Some(last)
+                    if last.src.end == piece.src.start
+                        && last.dest_interval().end == piece.dest =>
+                {
+                    last.src.end = piece.src.end;
+                }
+                _ => normalised.push(piece),
+            }
+        }
+
+        return Ok(IntervalMap { pieces: normalised });
+    }
+
+    /// The pieces, in order of source
+    pub fn pieces(&self) -> impl Iterator<Item = &Piece<T>> + '_ {
+        return self.pieces.iter();
+    }
+
+    /// The piece whose source holds `x`, if any
+    pub fn piece_at(&self, x: T) -> Option<&Piece<T>> {
+        let i = self.pieces.partition_point(|p| p.src.end <= x);
+        return self.pieces.get(i).filter(|p| p.src.contains(x));
+    }
+
+    /// Where `x` is mapped to
+    pub fn get(&self, x: T) -> T {
+        return match self.piece_at(x) {
+            Some(piece) => piece.get(x),
+            None => x,
+        };
+    }
+
+    /// Every value mapped to `y`, in increasing order. Pieces can overlap in what they map
+    /// onto, so there may be several.
+    pub fn preimage(&self, y: T) -> Vec<T> {
+        let mut xs: Vec<T> = self
+            .pieces
+            .iter()
+            .filter(|p| p.dest_interval().contains(y))
+            .map(|p| p.inverse(y))
+            .collect();
+        if self.piece_at(y).is_none() {
+            xs.push(y);
+        }
+        xs.sort();
+        return xs;
+    }
+
+    /// `within` cut where pieces start and end, each part with the piece covering it, or
+    /// `None` where values are left as they are
+    pub fn segments(&self, within: Interval<T>) -> Vec<(Interval<T>, Option<&Piece<T>>)> {
+        let mut segments = Vec::new();
+        if within.is_empty() {
+            return segments;
+        }
+        let mut pos = within.start;
+
+        let first = self.pieces.partition_point(|p| p.src.end <= within.start);
+        for piece in &self.pieces[first..] {
+            if piece.src.start >= within.end {
+                break;
+            }
+            if piece.src.start > pos {
+                segments.push((Interval::new(pos, piece.src.start), None));
+            }
+            let overlap = piece.src.intersection(&within).unwrap();
+            segments.push((overlap, Some(piece)));
+            pos = overlap.end;
+        }
+
+        if pos < within.end {
+            segments.push((Interval::new(pos, within.end), None));
+        }
+        return segments;
+    }
+
+    /// The map that applies `self`, then `then`
+    pub fn compose(&self, then: &IntervalMap<T>) -> IntervalMap<T> {
+        let mut pieces = Vec::new();
+
+        // Values `self` moves go wherever `then` sends their destination
+        for piece in &self.pieces {
+            for (dest, then_piece) in then.segments(piece.dest_interval()) {
+                pieces.push(Piece {
+                    src: Interval::with_len(piece.inverse(dest.start), dest.len()),
+                    dest: then_piece.map_or(dest.start, |p| p.get(dest.start)),
+                });
+            }
+        }
+
+        // Values `self` leaves alone only go through `then`
+        for then_piece in &then.pieces {
+            for (src, piece) in self.segments(then_piece.src) {
+                if piece.is_none() {
+                    pieces.extend(then_piece.restrict(src));
+                }
+            }
+        }
+
+        return IntervalMap::new(pieces).expect("composed pieces don't overlap");
+    }
+
+    /// Every value the map sends a member of `set` to
+    pub fn image(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        return set
+            .intervals()
+            .flat_map(|&interval| self.segments(interval))
+            .map(|(segment, piece)| match piece {
+                Some(piece) => piece.restrict(segment).unwrap().dest_interval(),
+                None => segment,
+            })
+            .collect();
+    }
+
+    /// Every value the map sends into `set`
+    pub fn inverse_image(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        // Values outside the pieces are in `set` already, or not at all
+        let sources: RangeSet<T> = self.pieces.iter().map(|p| p.src).collect();
+        let mut intervals = set.difference(&sources).intervals;
+
+        for piece in &self.pieces {
+            let dest = RangeSet::new([piece.dest_interval()]);
+            for y in set.intersection(&dest).intervals() {
+                intervals.push(Interval::with_len(piece.inverse(y.start), y.len()));
+            }
+        }
+        return RangeSet::new(intervals);
+    }
+}
+
+/// A set of integers stored as sorted, disjoint intervals. Touching intervals are merged
+/// and empty ones dropped, so equal sets have equal intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn empty() -> RangeSet<T> {
+        return RangeSet {
+            intervals: Vec::new(),
+        };
+    }
+
+    /// The union of `intervals`, which may overlap and come in any order
+    pub fn new(intervals: impl IntoIterator<Item = Interval<T>>) -> RangeSet<T> {
+        let mut sorted: Vec<Interval<T>> =
+            intervals.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_by_key(|i| i.start);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        return RangeSet { intervals: merged };
+    }
+
+    /// The intervals, in increasing order
+    pub fn intervals(&self) -> impl Iterator<Item = &Interval<T>> + '_ {
+        return self.intervals.iter();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end <= x);
+        return self
+            .intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(x));
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        return self.intervals.first().map(|interval| interval.start);
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        return RangeSet::new(self.intervals.iter().chain(&other.intervals).copied());
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intervals.extend(a.intersection(&b));
+            // Whichever ends first can't overlap anything further on the other side
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        return RangeSet { intervals };
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut pos = interval.start;
+            while j < other.intervals.len() && other.intervals[j].end <= pos {
+                j += 1;
+            }
+            // Cut out each of `other`'s intervals that starts before this one ends
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < interval.end {
+                let cut = other.intervals[k];
+                if cut.start > pos {
+                    intervals.push(Interval::new(pos, cut.start));
+                }
+                pos = pos.max(cut.end);
+                k += 1;
+            }
+            if pos < interval.end {
+                intervals.push(Interval::new(pos, interval.end));
+            }
+        }
+        return RangeSet { intervals };
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> RangeSet<T> {
+        return RangeSet::new(iter);
+    }
+}
@@ -0,0 +1,16 @@
+//! Helpers shared by every Advent of Code day crate.
+
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod output;
+pub mod solution;
+
+pub use error::{Error, ParseError};
+pub use grid::Grid;
+pub use input::{lines, load_input, numbered_lines, Line};
+pub use interval::{Interval, IntervalMap, Piece, RangeSet};
+pub use output::print_answer;
+pub use solution::{run, solve, Puzzle, Solution};
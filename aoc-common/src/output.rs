@@ -0,0 +1,6 @@
+use std::fmt::Display;
+
+/// Prints the answer to one part of a puzzle.
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("Part {}: {}", part, answer);
+}
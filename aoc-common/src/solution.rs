@@ -0,0 +1,84 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+
+use crate::bench::{bench, PhaseSamples};
+use crate::{load_input, print_answer, Error, ParseError};
+
+/// A day's puzzle: the input is parsed once and both parts are answered
+/// from the parsed form.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<impl Display, String>;
+    fn part2(input: &Self::Input) -> Result<impl Display, String>;
+}
+
+/// A `Solution` with its type erased, so that days can be dispatched at runtime.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    /// Name of an alternative implementation of the day, `None` for the main one
+    pub variant: Option<&'static str>,
+    /// Parses the input once and answers the requested parts, in order
+    pub solve: fn(&str, &[u8]) -> Result<Vec<String>, Error>,
+    /// Times each phase over the given number of iterations
+    pub bench: fn(&str, usize) -> Result<PhaseSamples, Error>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution>(year: u32, day: u32) -> Puzzle {
+        return Puzzle {
+            year,
+            day,
+            variant: None,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        };
+    }
+
+    /// An alternative implementation of a day, for comparing against the main one
+    pub const fn variant<S: Solution>(year: u32, day: u32, name: &'static str) -> Puzzle {
+        return Puzzle {
+            variant: Some(name),
+            ..Puzzle::new::<S>(year, day)
+        };
+    }
+}
+
+/// Parses `input` with `S` and returns the answers to `parts`, in order.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<String>, Error> {
+    let input = S::parse(input)?;
+
+    return parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&input).map(|a| a.to_string()),
+            2 => S::part2(&input).map(|a| a.to_string()),
+            _ => Err(format!("There is no part {}", part)),
+        })
+        .map(|answer| answer.map_err(Error::Solve))
+        .collect();
+}
+
+/// Solves both parts of `S` for the input file at `path` and prints the answers.
+pub fn run<S: Solution>(path: &str) -> ExitCode {
+    let answers = load_input(path)
+        .map_err(Error::Input)
+        .and_then(|contents| solve::<S>(&contents, &[1, 2]))
+        .map_err(|e| e.with_file(path));
+
+    match answers {
+        Ok(answers) => {
+            for (part, answer) in [1, 2].into_iter().zip(answers) {
+                print_answer(part, answer);
+            }
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::Grid;
+
+fn digits(input: &str) -> Grid<u32> {
+    return Grid::parse(input, |c| {
+        c.to_digit(10).ok_or(format!("Not a digit: {}", c))
+    })
+    .unwrap();
+}
+
+#[test]
+fn parse_is_row_major() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 4);
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+    assert_eq!(grid.column(3).count(), 0);
+}
+
+#[test]
+fn parse_errors_point_at_the_cell() {
+    let bad_cell = Grid::parse("12\n3x\n", |c| {
+        c.to_digit(10).ok_or("Not a digit".to_string())
+    });
+    let e = bad_cell.unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 2, "x"));
+
+    let ragged = Grid::parse("123\n45\n", Ok::<char, String>).unwrap_err();
+    assert_eq!((ragged.line, ragged.column), (2, 3));
+
+    let long = Grid::parse("12\n345\n", Ok::<char, String>).unwrap_err();
+    assert_eq!((long.line, long.column, long.token.as_str()), (2, 3, "5"));
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = digits("123\n456\n789");
+    let mut corner: Vec<_> = grid.neighbours8((0, 0)).collect();
+    corner.sort();
+    assert_eq!(corner, vec![(0, 1), (1, 0), (1, 1)]);
+
+    assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.offset((2, 2), (1, 0)), None);
+    assert_eq!(grid.offset((2, 2), (-2, -1)), Some((0, 1)));
+}
+
+#[test]
+fn region_is_clipped() {
+    let grid = digits("123\n456\n789");
+    let region: Vec<u32> = grid.region(1..5, 2..9).map(|(_, &d)| d).collect();
+    assert_eq!(region, vec![6, 9]);
+    assert_eq!(grid.region(3..5, 0..3).count(), 0);
+}
@@ -0,0 +1,256 @@
+use aoc_common::{Interval, IntervalMap, Piece, RangeSet};
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+fn piece(src: i64, dest: i64, len: i64) -> Piece<i64> {
+    return Piece {
+        src: Interval::with_len(src, len),
+        dest,
+    };
+}
+
+/// The day 5 example's seed-to-soil map
+fn seed_to_soil() -> IntervalMap<i64> {
+    return IntervalMap::new([piece(98, 50, 2), piece(50, 52, 48)]).unwrap();
+}
+
+#[test]
+fn intervals_are_half_open() {
+    let interval = Interval::new(3u8, 7);
+    assert_eq!(interval.len(), 4);
+    assert!(interval.contains(3) && !interval.contains(7));
+    assert_eq!(
+        interval.intersection(&Interval::new(5, 10)),
+        Some(Interval::new(5, 7))
+    );
+    assert_eq!(interval.intersection(&Interval::new(7, 10)), None);
+    assert!(Interval::new(2, 2).is_empty());
+    assert_eq!(
+        Interval::checked_with_len(250u8, 5),
+        Some(Interval::new(250, 255))
+    );
+    assert_eq!(Interval::checked_with_len(250u8, 6), None);
+    assert_eq!(Interval::checked_with_len(0i8, -1), None);
+}
+
+#[test]
+#[should_panic(expected = "backwards")]
+fn backwards_intervals_panic() {
+    Interval::new(3, 2);
+}
+
+#[test]
+fn lookups_both_ways() {
+    let map = seed_to_soil();
+    assert_eq!(
+        [79, 14, 55, 13, 98, 99, 100].map(|x| map.get(x)),
+        [81, 14, 57, 13, 50, 51, 100]
+    );
+
+    // 50 moves to 52, so only 98 is mapped onto it
+    assert_eq!(map.preimage(50), vec![98]);
+    assert_eq!(map.preimage(98), vec![96]);
+    assert_eq!(map.preimage(7), vec![7]);
+
+    // 10 is left alone, and 0 is moved onto it
+    let map = IntervalMap::new([piece(0, 10, 5)]).unwrap();
+    assert_eq!(map.preimage(10), vec![0, 10]);
+    assert_eq!(map.preimage(3), Vec::<i64>::new());
+}
+
+#[test]
+fn maps_are_normalised() {
+    let map = IntervalMap::new([piece(10, 20, 5), piece(0, 0, 3), piece(15, 25, 5)]).unwrap();
+    assert_eq!(map.pieces().collect::<Vec<_>>(), vec![&piece(10, 20, 10)]);
+
+    let overlapping = IntervalMap::new([piece(0, 10, 5), piece(4, 20, 2)]);
+    assert_eq!(
+        overlapping.unwrap_err(),
+        "Pieces from Interval { start: 0, end: 5 } and Interval { start: 4, end: 6 } overlap"
+    );
+
+    // Unsigned maps can shift down as well as up
+    let map = IntervalMap::new([Piece {
+        src: Interval::new(10u32, 20),
+        dest: 0,
+    }])
+    .unwrap();
+    assert_eq!((map.get(15), map.get(25)), (5, 25));
+}
+
+#[test]
+fn segments_cover_the_interval() {
+    let map = seed_to_soil();
+    let segments: Vec<(Interval<i64>, bool)> = map
+        .segments(Interval::new(40, 105))
+        .into_iter()
+        .map(|(interval, piece)| (interval, piece.is_some()))
+        .collect();
+    assert_eq!(
+        segments,
+        vec![
+            (Interval::new(40, 50), false),
+            (Interval::new(50, 98), true),
+            (Interval::new(98, 100), true),
+            (Interval::new(100, 105), false),
+        ]
+    );
+}
+
+#[test]
+fn composition_matches_applying_in_turn() {
+    // The example's soil-to-fertilizer map
+    let then = IntervalMap::new([piece(15, 0, 37), piece(52, 37, 2), piece(0, 39, 15)]).unwrap();
+    let composed = seed_to_soil().compose(&then);
+    assert_eq!([79, 14, 55, 13].map(|x| composed.get(x)), [81, 53, 57, 52]);
+    assert_eq!(
+        seed_to_soil().compose(&IntervalMap::identity()),
+        seed_to_soil()
+    );
+}
+
+fn set(intervals: &[(i64, i64)]) -> RangeSet<i64> {
+    return intervals
+        .iter()
+        .map(|&(s, e)| Interval::new(s, e))
+        .collect();
+}
+
+#[test]
+fn range_sets_are_normalised() {
+    let normalised = set(&[(8, 10), (0, 3), (2, 5), (5, 6), (7, 7)]);
+    assert_eq!(normalised, set(&[(0, 6), (8, 10)]));
+    assert_eq!(normalised.intervals().count(), 2);
+    assert!(normalised.contains(5) && !normalised.contains(6) && !normalised.contains(7));
+    assert_eq!(normalised.min(), Some(0));
+    assert_eq!(RangeSet::<i64>::empty().min(), None);
+}
+
+#[test]
+fn range_set_algebra() {
+    let a = set(&[(0, 10), (20, 30)]);
+    let b = set(&[(5, 25), (28, 29)]);
+    assert_eq!(a.union(&b), set(&[(0, 30)]));
+    assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 29)]));
+    assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28), (29, 30)]));
+    assert_eq!(b.difference(&a), set(&[(10, 20)]));
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn images_include_values_left_alone() {
+    let map = seed_to_soil();
+    // 40..50 is left alone, 50..60 moves up by 2, 98..100 moves to 50..52
+    let image = map.image(&set(&[(40, 60), (98, 100), (200, 201)]));
+    assert_eq!(image, set(&[(40, 62), (200, 201)]));
+}
+
+#[test]
+fn inverse_images_skip_values_moved_away() {
+    let map = seed_to_soil();
+    // 50..52 comes from 98..100 and 52..54 from 50..52, while 50..52 itself moves up
+    let inverse = map.inverse_image(&set(&[(50, 54), (100, 101)]));
+    assert_eq!(inverse, set(&[(50, 52), (98, 101)]));
+}
+
+/// A random map over small values, so that pieces often touch and overlap
+#[derive(Clone, Debug)]
+struct SmallMap(IntervalMap<i64>);
+
+impl Arbitrary for SmallMap {
+    fn arbitrary(g: &mut Gen) -> SmallMap {
+        let mut pieces = Vec::new();
+        let mut start = -20;
+        for _ in 0..u8::arbitrary(g) % 6 {
+            start += (u8::arbitrary(g) % 10) as i64;
+            let len = (u8::arbitrary(g) % 10) as i64;
+            let dest = (u8::arbitrary(g) % 60) as i64 - 30;
+            pieces.push(piece(start, dest, len));
+            start += len;
+        }
+        return SmallMap(IntervalMap::new(pieces).unwrap());
+    }
+}
+
+/// A random set over small values
+#[derive(Clone, Debug)]
+struct SmallSet(RangeSet<i64>);
+
+impl Arbitrary for SmallSet {
+    fn arbitrary(g: &mut Gen) -> SmallSet {
+        let intervals: Vec<Interval<i64>> = (0..u8::arbitrary(g) % 6)
+            .map(|_| {
+                let start = (u8::arbitrary(g) % 60) as i64 - 30;
+                Interval::with_len(start, (u8::arbitrary(g) % 12) as i64)
+            })
+            .collect();
+        return SmallSet(RangeSet::new(intervals));
+    }
+}
+
+fn small(x: i8) -> i64 {
+    return x as i64 / 2;
+}
+
+quickcheck! {
+    fn composition_is_application(f: SmallMap, g: SmallMap, x: i8) -> bool {
+        let x = small(x);
+        return f.0.compose(&g.0).get(x) == g.0.get(f.0.get(x));
+    }
+
+    fn composition_is_associative(f: SmallMap, g: SmallMap, h: SmallMap) -> bool {
+        return f.0.compose(&g.0).compose(&h.0) == f.0.compose(&g.0.compose(&h.0));
+    }
+
+    fn preimage_inverts_lookup(f: SmallMap, x: i8, y: i8) -> bool {
+        let (x, y) = (small(x), small(y));
+        let preimage = f.0.preimage(y);
+        return f.0.preimage(f.0.get(x)).contains(&x)
+            && preimage.iter().all(|&x| f.0.get(x) == y)
+            && preimage.windows(2).all(|w| w[0] < w[1]);
+    }
+
+    fn pieces_are_sorted_and_distinct(f: SmallMap) -> bool {
+        let pieces: Vec<&Piece<i64>> = f.0.pieces().collect();
+        return pieces.iter().all(|p| !p.src.is_empty() && p.dest != p.src.start)
+            && pieces.windows(2).all(|w| {
+                w[0].src.end < w[1].src.start
+                    || (w[0].src.end == w[1].src.start && w[0].dest_interval().end != w[1].dest)
+            });
+    }
+
+    fn segments_partition(f: SmallMap, start: i8, len: u8) -> bool {
+        let within = Interval::with_len(small(start), len as i64 / 4);
+        let segments = f.0.segments(within);
+        let covered: i64 = segments.iter().map(|(s, _)| s.len()).sum();
+        return covered == within.len()
+            && segments.windows(2).all(|w| w[0].0.end == w[1].0.start)
+            && segments.iter().all(|(s, piece)| match piece {
+                Some(p) => p.src.intersection(s) == Some(*s),
+                None => (s.start..s.end).all(|x| f.0.piece_at(x).is_none()),
+            });
+    }
+
+    fn set_operations_match_membership(a: SmallSet, b: SmallSet, x: i8) -> bool {
+        let x = small(x);
+        let (in_a, in_b) = (a.0.contains(x), b.0.contains(x));
+        return a.0.union(&b.0).contains(x) == (in_a || in_b)
+            && a.0.intersection(&b.0).contains(x) == (in_a && in_b)
+            && a.0.difference(&b.0).contains(x) == (in_a && !in_b);
+    }
+
+    fn set_operations_are_normalised(a: SmallSet, b: SmallSet) -> bool {
+        let results = [a.0.union(&b.0), a.0.intersection(&b.0), a.0.difference(&b.0)];
+        return results.iter().all(|r| *r == RangeSet::new(r.intervals().copied()));
+    }
+
+    fn image_is_exact(f: SmallMap, s: SmallSet, y: i8) -> bool {
+        let y = small(y);
+        let image = f.0.image(&s.0);
+        return image.contains(y) == f.0.preimage(y).iter().any(|&x| s.0.contains(x));
+    }
+
+    fn inverse_image_is_exact(f: SmallMap, s: SmallSet, x: i8) -> bool {
+        let x = small(x);
+        return f.0.inverse_image(&s.0).contains(x) == s.0.contains(f.0.get(x));
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use sha2::{Digest, Sha256};
+
+/// Checked-in manifest of known answers, one `year day part sha256 answer` entry per line
+pub const MANIFEST_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+const HEADER: &str = "\
+# Known answers, keyed by the SHA-256 of the input they were computed from.
+# Maintained by `aoc verify --record`.
+# year day part sha256 answer
+";
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct AnswerKey {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub input_hash: String,
+}
+
+#[derive(Default)]
+pub struct Manifest {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl Manifest {
+    /// Loads the manifest at `path`. A missing file is an empty manifest.
+    pub fn load(path: &str) -> Result<Manifest, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Manifest::default()),
+            Err(e) => return Err(format!("Unable to read {}: {}", path, e)),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(5, ' ').collect();
+            let bad_entry = || {
+                format!(
+                    "{}:{}: expected \"year day part sha256 answer\"",
+                    path,
+                    i + 1
+                )
+            };
+            if fields.len() != 5 {
+                return Err(bad_entry());
+            }
+
+            let key = AnswerKey {
+                year: fields[0].parse().map_err(|_| bad_entry())?,
+                day: fields[1].parse().map_err(|_| bad_entry())?,
+                part: fields[2].parse().map_err(|_| bad_entry())?,
+                input_hash: fields[3].to_string(),
+            };
+            answers.insert(key, fields[4].to_string());
+        }
+
+        return Ok(Manifest { answers });
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let mut contents = String::from(HEADER);
+        for (key, answer) in &self.answers {
+            contents += &format!(
+                "{} {} {} {} {}\n",
+                key.year, key.day, key.part, key.input_hash, answer
+            );
+        }
+
+        return fs::write(path, contents).map_err(|e| format!("Unable to write {}: {}", path, e));
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        return self.answers.get(key).map(|a| a.as_str());
+    }
+
+    pub fn insert(&mut self, key: AnswerKey, answer: String) {
+        self.answers.insert(key, answer);
+    }
+}
+
+/// Hex SHA-256 of a puzzle input
+pub fn hash_input(contents: &str) -> String {
+    return Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+}
@@ -0,0 +1,66 @@
+use std::fs;
+
+use aoc_common::bench::Summary;
+use serde::{Deserialize, Serialize};
+
+/// Benchmark results saved by `aoc bench --save`, for later runs to compare against
+#[derive(Serialize, Deserialize, Default)]
+pub struct Baseline {
+    pub iterations: usize,
+    pub results: Vec<PhaseResult>,
+}
+
+/// Timing summary of one phase (parse, part1 or part2) of one puzzle
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PhaseResult {
+    pub year: u32,
+    pub day: u32,
+    pub variant: Option<String>,
+    pub phase: String,
+    pub median_ns: f64,
+    pub p95_ns: f64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+    pub min_ns: f64,
+}
+
+impl PhaseResult {
+    pub fn new(year: u32, day: u32, variant: Option<&str>, phase: &str, summary: &Summary) -> Self {
+        return PhaseResult {
+            year,
+            day,
+            variant: variant.map(String::from),
+            phase: phase.to_string(),
+            median_ns: summary.median,
+            p95_ns: summary.p95,
+            mean_ns: summary.mean,
+            stddev_ns: summary.stddev,
+            min_ns: summary.min,
+        };
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Baseline, String> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path, e))?;
+        return serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid baseline {}: {}", path, e));
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        return fs::write(path, contents + "\n")
+            .map_err(|e| format!("Unable to write {}: {}", path, e));
+    }
+
+    /// The saved result for the same puzzle, variant and phase as `result`
+    pub fn find(&self, result: &PhaseResult) -> Option<&PhaseResult> {
+        return self.results.iter().find(|r| {
+            r.year == result.year
+                && r.day == result.day
+                && r.variant == result.variant
+                && r.phase == result.phase
+        });
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/dklahn99/advent-of-code-2023 aoc runner";
+
+/// Authenticated client for the Advent of Code site
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client, String> {
+        let session = config.session.clone().ok_or_else(|| {
+            "No session token: set AOC_SESSION or `session` in the config file".to_string()
+        })?;
+
+        return Ok(Client {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: config.base_url.clone(),
+            session,
+        });
+    }
+
+    fn get(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| format!("GET {} failed: {}", url, e))?;
+
+        return response
+            .into_string()
+            .map_err(|e| format!("Unable to read the response from {}: {}", url, e));
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form)
+            .map_err(|e| format!("POST {} failed: {}", url, e))?;
+
+        return response
+            .into_string()
+            .map_err(|e| format!("Unable to read the response from {}: {}", url, e));
+    }
+
+    /// Downloads a day's puzzle input
+    pub fn input(&self, year: u32, day: u32) -> Result<String, String> {
+        return self.get(&format!("/{}/day/{}/input", year, day));
+    }
+
+    /// Submits an answer, returning the page the site responds with
+    pub fn answer(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<String, String> {
+        return self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::days::WORKSPACE_DIR;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code site.
+///
+/// Read from a `key = value` config file (`$AOC_CONFIG`, or `~/.config/aoc/config`),
+/// with `AOC_SESSION`, `AOC_BASE_URL` and `AOC_INPUTS_DIR` taking precedence.
+pub struct Config {
+    /// Value of the site's `session` cookie
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Root of the input cache, holding one directory per year and the submission log
+    pub inputs_dir: PathBuf,
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    return env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config/aoc/config"));
+}
+
+fn read_config_file(path: &Path) -> Result<HashMap<String, String>, String> {
+    let mut values = HashMap::new();
+    if !path.exists() {
+        return Ok(values);
+    }
+
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Unable to read {:?}: {}", path, e))?;
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("{:?}:{}: expected \"key = value\"", path, i + 1))?;
+        values.insert(key.trim().to_string(), value.trim().to_string());
+    }
+
+    return Ok(values);
+}
+
+impl Config {
+    pub fn load() -> Result<Config, String> {
+        let file_values = match config_path() {
+            Some(path) => read_config_file(&path)?,
+            None => HashMap::new(),
+        };
+        let get = |key: &str, env_key: &str| {
+            return env::var(env_key)
+                .ok()
+                .or_else(|| file_values.get(key).cloned())
+                .filter(|v| !v.is_empty());
+        };
+
+        return Ok(Config {
+            session: get("session", "AOC_SESSION"),
+            base_url: get("base_url", "AOC_BASE_URL")
+                .unwrap_or(DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            inputs_dir: get("inputs_dir", "AOC_INPUTS_DIR")
+                .map(PathBuf::from)
+                .unwrap_or(PathBuf::from(WORKSPACE_DIR).join("inputs")),
+        });
+    }
+}
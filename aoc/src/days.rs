@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::Puzzle;
+
+use crate::config::Config;
+use crate::fetch;
+
+/// Every implemented puzzle, in the order `--all` runs them.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day1::Day1>(2023, 1),
+    Puzzle::new::<day2::Day2>(2023, 2),
+    Puzzle::new::<day3::Day3>(2023, 3),
+    Puzzle::new::<day4::Day4>(2023, 4),
+    Puzzle::new::<day5::Day5>(2023, 5),
+    Puzzle::new::<day6::Day6>(2023, 6),
+    Puzzle::new::<day7::Day7>(2023, 7),
+    Puzzle::new::<day8::Day8>(2023, 8),
+    Puzzle::new::<day9::Day9>(2023, 9),
+    Puzzle::new::<day10::Day10>(2023, 10),
+];
+
+/// Alternative implementations, only used for benchmarking against the main ones
+pub const VARIANTS: &[Puzzle] = &[Puzzle::variant::<day6::Day6BruteForce>(
+    2023,
+    6,
+    "brute-force",
+)];
+
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+pub fn find(year: u32, day: u32) -> Option<&'static Puzzle> {
+    return PUZZLES.iter().find(|p| p.year == year && p.day == day);
+}
+
+/// A day's crate, whether or not it has a solution yet
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    return PathBuf::from(format!("{}/{}/day{}", WORKSPACE_DIR, year, day));
+}
+
+/// Directory holding a day's input and examples
+pub fn input_dir(puzzle: &Puzzle) -> PathBuf {
+    return day_dir(puzzle.year, puzzle.day).join("src");
+}
+
+/// The checked-in puzzle input for a day, or the downloaded one if nothing is checked in
+pub fn default_input(puzzle: &Puzzle) -> String {
+    let checked_in = input_dir(puzzle).join("input.txt");
+
+    if !checked_in.exists() {
+        if let Ok(config) = Config::load() {
+            let cached = fetch::cache_path(&config, puzzle.year, puzzle.day);
+            if cached.exists() {
+                return cached.to_string_lossy().into_owned();
+            }
+        }
+    }
+    return checked_in.to_string_lossy().into_owned();
+}
+
+/// The puzzle input followed by every `test*.txt` example, for days that have them
+pub fn all_inputs(puzzle: &Puzzle) -> Result<Vec<PathBuf>, String> {
+    let dir = input_dir(puzzle);
+    let entries = fs::read_dir(&dir).map_err(|e| format!("Unable to read {:?}: {}", dir, e))?;
+
+    let mut examples: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            return name.starts_with("test") && name.ends_with(".txt");
+        })
+        .collect();
+    examples.sort();
+
+    let mut inputs = vec![PathBuf::from(default_input(puzzle))];
+    inputs.retain(|path| path.exists());
+    inputs.append(&mut examples);
+    return Ok(inputs);
+}
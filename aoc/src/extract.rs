@@ -0,0 +1,91 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::page::Page;
+
+/// What was written for a saved puzzle page
+pub struct Extracted {
+    pub markdown: PathBuf,
+    /// Fixture holding each distinct example block, in page order
+    pub fixtures: Vec<PathBuf>,
+    /// For each part, the fixture with its first example and the answer given for it
+    pub part_examples: Vec<Option<(PathBuf, String)>>,
+}
+
+/// The saved `.html` page in a day's directory
+pub fn find_page(dir: &Path) -> Result<PathBuf, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Unable to read {:?}: {}", dir, e))?;
+
+    let mut pages: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "html"))
+        .collect();
+    pages.sort();
+
+    return pages
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("No saved puzzle page in {:?}", dir));
+}
+
+/// The `test*.txt` fixture in `src_dir` holding `example`, writing a new one if none does
+fn fixture_for(src_dir: &Path, example: &str) -> Result<PathBuf, String> {
+    for n in 1.. {
+        let name = match n {
+            1 => "test.txt".to_string(),
+            n => format!("test{}.txt", n),
+        };
+        let path = src_dir.join(name);
+
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing.trim_end() == example.trim_end() => return Ok(path),
+            Ok(_) => continue,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                fs::write(&path, example)
+                    .map_err(|e| format!("Unable to write {:?}: {}", path, e))?;
+                return Ok(path);
+            }
+            Err(e) => return Err(format!("Unable to read {:?}: {}", path, e)),
+        }
+    }
+    unreachable!();
+}
+
+/// Writes the page's description to `puzzle.md` in `dir`, and each distinct example
+/// block to a `test*.txt` fixture in `dir/src`, reusing fixtures that already hold it
+pub fn extract(page: &Page, dir: &Path) -> Result<Extracted, String> {
+    let markdown = dir.join("puzzle.md");
+    fs::write(&markdown, page.to_markdown())
+        .map_err(|e| format!("Unable to write {:?}: {}", markdown, e))?;
+
+    let src_dir = dir.join("src");
+    fs::create_dir_all(&src_dir).map_err(|e| format!("Unable to create {:?}: {}", src_dir, e))?;
+
+    let mut fixtures: Vec<PathBuf> = Vec::new();
+    let mut part_examples = Vec::new();
+    for part in &page.parts {
+        let mut first = None;
+        for example in &part.examples {
+            let fixture = fixture_for(&src_dir, example)?;
+            if first.is_none() {
+                first = Some(fixture.clone());
+            }
+            if !fixtures.contains(&fixture) {
+                fixtures.push(fixture);
+            }
+        }
+
+        part_examples.push(match (first, part.example_answer()) {
+            (Some(fixture), Some(answer)) => Some((fixture, answer.to_string())),
+            _ => None,
+        });
+    }
+
+    return Ok(Extracted {
+        markdown,
+        fixtures,
+        part_examples,
+    });
+}
@@ -0,0 +1,34 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::client::Client;
+use crate::config::Config;
+
+/// Where a downloaded input is cached: one directory per year under the inputs directory
+pub fn cache_path(config: &Config, year: u32, day: u32) -> PathBuf {
+    return config
+        .inputs_dir
+        .join(year.to_string())
+        .join(format!("day{}.txt", day));
+}
+
+/// Returns the path to a day's input, downloading it only if it isn't cached yet
+pub fn fetch_input(config: &Config, year: u32, day: u32) -> Result<PathBuf, String> {
+    let path = cache_path(config, year, day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let contents = Client::new(config)?.input(year, day)?;
+
+    let dir = path
+        .parent()
+        .expect("Cache paths always have a year directory");
+    fs::create_dir_all(dir).map_err(|e| format!("Unable to create {:?}: {}", dir, e))?;
+
+    // Write then rename, so an interrupted download is never mistaken for a cached input
+    let partial = path.with_extension("part");
+    fs::write(&partial, contents).map_err(|e| format!("Unable to write {:?}: {}", partial, e))?;
+    fs::rename(&partial, &path).map_err(|e| format!("Unable to write {:?}: {}", path, e))?;
+    return Ok(path);
+}
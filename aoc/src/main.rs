@@ -0,0 +1,563 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use answers::{hash_input, AnswerKey, Manifest, MANIFEST_FILE};
+use aoc_common::bench::Summary;
+use aoc_common::{load_input, print_answer, Error, Puzzle};
+use baseline::{Baseline, PhaseResult};
+use clap::{Args, Parser, Subcommand};
+use config::Config;
+use page::Page;
+use submit::Verdict;
+use verify::Outcome;
+
+mod answers;
+mod baseline;
+mod client;
+mod config;
+mod days;
+mod extract;
+mod fetch;
+mod page;
+mod scaffold;
+mod submit;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every implemented day with --all
+    Run(RunArgs),
+    /// Check answers for every input and example against the known-answer manifest
+    Verify(VerifyArgs),
+    /// Time parsing and each part of every day separately
+    Bench(BenchArgs),
+    /// Download a day's puzzle input into the input cache
+    Fetch(FetchArgs),
+    /// Submit an answer, unless it has already been judged
+    Submit(SubmitArgs),
+    /// Convert a saved puzzle page to Markdown and pull out its examples and their answers
+    Extract(ExtractArgs),
+    /// Create a new day's crate from the template and register it with the workspace and runner
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// Only solve this part (both parts by default)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "all")]
+    part: Option<u8>,
+
+    /// Input file, or `-` for stdin (defaults to the day's src/input.txt)
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Solve every implemented day of the year and print a summary table
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[arg(long)]
+    year: Option<u32>,
+
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Add the answers for inputs the manifest doesn't know yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[arg(long)]
+    year: Option<u32>,
+
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Timed runs of each day
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// Save the results as a JSON baseline
+    #[arg(long)]
+    save: Option<String>,
+
+    /// Compare against a JSON baseline saved by an earlier run
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Fraction by which a median may exceed the baseline's before it counts as a regression
+    #[arg(long, default_value_t = 0.1)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(long)]
+    day: u32,
+
+    /// Site to download from, overriding the config
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(long)]
+    day: u32,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit (solves the day's input by default)
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Input file to solve, or `-` for stdin (defaults to the day's src/input.txt)
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+}
+
+#[derive(Args)]
+struct ExtractArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(long)]
+    day: u32,
+
+    /// Saved page (defaults to the .html file in the day's directory)
+    #[arg(long)]
+    html: Option<String>,
+
+    /// Where to write puzzle.md and the src/test*.txt fixtures (defaults to the day's directory)
+    #[arg(long)]
+    dir: Option<String>,
+
+    /// Add the example answers to the known-answer manifest
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long, default_value_t = 2023)]
+    year: u32,
+
+    #[arg(long)]
+    day: u32,
+
+    /// Workspace to add the day to
+    #[arg(long, default_value = days::WORKSPACE_DIR, hide = true)]
+    workspace: String,
+}
+
+/// One-line description of an error. Parse diagnostics span several lines,
+/// so only their location and message are kept.
+fn error_summary(e: &Error) -> String {
+    match e {
+        Error::Parse(e) => return format!("error: line {}: {}", e.line, e.message),
+        e => return format!("error: {}", e),
+    }
+}
+
+fn run_one(args: &RunArgs, day: u32) -> Result<(), String> {
+    let puzzle = days::find(args.year, day)
+        .ok_or_else(|| format!("No solution for {} day {}", args.year, day))?;
+
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| days::default_input(puzzle));
+    let contents = load_input(&path)?;
+
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let answers = (puzzle.solve)(&contents, &parts).map_err(|e| e.with_file(&path).to_string())?;
+
+    for (part, answer) in parts.into_iter().zip(answers) {
+        print_answer(part, answer);
+    }
+    return Ok(());
+}
+
+/// Solves both parts of a puzzle from its default input, reporting errors in place of answers
+fn solve_for_summary(puzzle: &Puzzle) -> ([String; 2], Duration) {
+    let start = Instant::now();
+    let result = load_input(&days::default_input(puzzle))
+        .map_err(Error::Input)
+        .and_then(|c| (puzzle.solve)(&c, &[1, 2]));
+    let elapsed = start.elapsed();
+
+    let answers = match result {
+        Ok(answers) => [answers[0].clone(), answers[1].clone()],
+        Err(e) => [error_summary(&e), String::new()],
+    };
+    return (answers, elapsed);
+}
+
+fn run_all(year: u32) -> Result<(), String> {
+    let rows: Vec<(u32, [String; 2], Duration)> = days::PUZZLES
+        .iter()
+        .filter(|p| p.year == year)
+        .map(|p| {
+            let (answers, elapsed) = solve_for_summary(p);
+            (p.day, answers, elapsed)
+        })
+        .collect();
+
+    if rows.is_empty() {
+        return Err(format!("No solutions for {}", year));
+    }
+
+    let width1 = rows.iter().map(|r| r.1[0].len()).chain([6]).max().unwrap();
+    let width2 = rows.iter().map(|r| r.1[1].len()).chain([6]).max().unwrap();
+
+    println!(
+        "{:>3}  {:<width1$}  {:<width2$}  {:>10}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+    for (day, answers, elapsed) in &rows {
+        println!(
+            "{:>3}  {:<width1$}  {:<width2$}  {:>10}",
+            day,
+            answers[0],
+            answers[1],
+            format!("{:.2?}", elapsed),
+        );
+    }
+
+    let total: Duration = rows.iter().map(|r| r.2).sum();
+    println!(
+        "{:>3}  {:<width1$}  {:<width2$}  {:>10}",
+        "",
+        "",
+        "",
+        format!("{:.2?}", total)
+    );
+    return Ok(());
+}
+
+fn run_verify(args: &VerifyArgs) -> Result<(), String> {
+    let mut manifest = Manifest::load(MANIFEST_FILE)?;
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    let mut new_answers = Vec::new();
+
+    let puzzles = days::PUZZLES
+        .iter()
+        .filter(|p| args.year.is_none_or(|year| p.year == year))
+        .filter(|p| args.day.is_none_or(|day| p.day == day));
+
+    for puzzle in puzzles {
+        for check in verify::verify(puzzle, &manifest)? {
+            let answer = match &check.answer {
+                Ok(answer) => answer.clone(),
+                Err(e) => error_summary(e),
+            };
+
+            let (status, detail) = match check.outcome {
+                Outcome::Pass => {
+                    passed += 1;
+                    ("PASS", answer)
+                }
+                Outcome::Fail { expected } => {
+                    failed += 1;
+                    ("FAIL", format!("expected {}, got {}", expected, answer))
+                }
+                Outcome::Unknown => {
+                    unknown += 1;
+                    if let (true, Ok(answer)) = (args.record, check.answer) {
+                        new_answers.push((check.key.clone(), answer));
+                    }
+                    ("UNKNOWN", answer)
+                }
+            };
+
+            println!(
+                "{} day {:>2} part {}  {:<16} {:<8} {}",
+                check.key.year,
+                check.key.day,
+                check.key.part,
+                check.file.file_name().unwrap_or_default().to_string_lossy(),
+                status,
+                detail,
+            );
+        }
+    }
+
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+
+    if !new_answers.is_empty() {
+        println!(
+            "Recording {} new answers in {}",
+            new_answers.len(),
+            MANIFEST_FILE
+        );
+        for (key, answer) in new_answers {
+            manifest.insert(key, answer);
+        }
+        manifest.save(MANIFEST_FILE)?;
+    }
+
+    if failed > 0 {
+        return Err(format!("{} answers differ from the manifest", failed));
+    }
+    return Ok(());
+}
+
+fn format_nanos(nanos: f64) -> String {
+    return format!("{:.2?}", Duration::from_nanos(nanos as u64));
+}
+
+fn run_bench(args: &BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+
+    let puzzles = days::PUZZLES
+        .iter()
+        .chain(days::VARIANTS)
+        .filter(|p| args.year.is_none_or(|year| p.year == year))
+        .filter(|p| args.day.is_none_or(|day| p.day == day));
+
+    let mut results = Vec::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:>4}  {:<12} {:<6} {:>10} {:>10} {:>10}  vs baseline",
+        "Day", "Variant", "Phase", "Median", "p95", "Stddev"
+    );
+    for puzzle in puzzles {
+        let contents = load_input(&days::default_input(puzzle))?;
+        let samples = (puzzle.bench)(&contents, args.iterations as usize)
+            .map_err(|e| format!("{} day {}: {}", puzzle.year, puzzle.day, error_summary(&e)))?;
+
+        let phases = [
+            ("parse", &samples.parse),
+            ("part1", &samples.part1),
+            ("part2", &samples.part2),
+        ];
+        for (phase, phase_samples) in phases {
+            let summary = Summary::from_samples(phase_samples);
+            let result = PhaseResult::new(puzzle.year, puzzle.day, puzzle.variant, phase, &summary);
+
+            let comparison = match baseline.as_ref().and_then(|b| b.find(&result)) {
+                None => String::new(),
+                // Too fast to time, so there is nothing to compare against
+                Some(old) if old.median_ns <= 0.0 => "no baseline timing".to_string(),
+                Some(old) => {
+                    let change = result.median_ns / old.median_ns - 1.0;
+                    if change > args.threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", change * 100.0)
+                    } else {
+                        format!("{:+.1}%", change * 100.0)
+                    }
+                }
+            };
+
+            println!(
+                "{:>4}  {:<12} {:<6} {:>10} {:>10} {:>10}  {}",
+                puzzle.day,
+                puzzle.variant.unwrap_or(""),
+                phase,
+                format_nanos(summary.median),
+                format_nanos(summary.p95),
+                format_nanos(summary.stddev),
+                comparison,
+            );
+            results.push(result);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        let new_baseline = Baseline {
+            iterations: args.iterations as usize,
+            results,
+        };
+        new_baseline.save(path)?;
+        println!("Saved baseline to {}", path);
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} phases regressed by more than {:.0}%",
+            regressions,
+            args.threshold * 100.0
+        ));
+    }
+    return Ok(());
+}
+
+fn run_fetch(args: &FetchArgs) -> Result<(), String> {
+    let mut config = Config::load()?;
+    if let Some(base_url) = &args.base_url {
+        config.base_url = base_url.trim_end_matches('/').to_string();
+    }
+
+    let path = fetch::fetch_input(&config, args.year, args.day)?;
+    println!("{}", path.display());
+    return Ok(());
+}
+
+fn run_submit(args: &SubmitArgs) -> Result<(), String> {
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let puzzle = days::find(args.year, args.day)
+                .ok_or_else(|| format!("No solution for {} day {}", args.year, args.day))?;
+            let path = args
+                .input
+                .clone()
+                .unwrap_or_else(|| days::default_input(puzzle));
+            let contents = load_input(&path)?;
+            let answers = (puzzle.solve)(&contents, &[args.part])
+                .map_err(|e| e.with_file(&path).to_string())?;
+            answers[0].clone()
+        }
+    };
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("Not a valid answer: {:?}", answer));
+    }
+
+    let config = Config::load()?;
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, args.year, args.day, args.part
+    );
+    let verdict = submit::submit(&config, args.year, args.day, args.part, &answer)?;
+    println!("{}", verdict);
+
+    if verdict != Verdict::Correct {
+        return Err(format!("{} was not accepted", answer));
+    }
+    return Ok(());
+}
+
+fn run_extract(args: &ExtractArgs) -> Result<(), String> {
+    let dir = args
+        .dir
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| days::day_dir(args.year, args.day));
+    let html_path = match &args.html {
+        Some(path) => PathBuf::from(path),
+        None => extract::find_page(&dir)?,
+    };
+
+    let html = load_input(&html_path.to_string_lossy())?;
+    let page = Page::parse(&html).map_err(|e| format!("{:?}: {}", html_path, e))?;
+    let extracted = extract::extract(&page, &dir)?;
+
+    println!("Wrote {}", extracted.markdown.display());
+    for fixture in &extracted.fixtures {
+        println!("Example {}", fixture.display());
+    }
+
+    let mut manifest = Manifest::load(MANIFEST_FILE)?;
+    let mut recorded = 0;
+    for (i, example) in extracted.part_examples.iter().enumerate() {
+        let part = i as u8 + 1;
+        let Some((fixture, answer)) = example else {
+            println!("Part {}: no example answer", part);
+            continue;
+        };
+        println!(
+            "Part {}: {} -> {}",
+            part,
+            fixture.file_name().unwrap_or_default().to_string_lossy(),
+            answer
+        );
+
+        let key = AnswerKey {
+            year: args.year,
+            day: args.day,
+            part,
+            input_hash: hash_input(&load_input(&fixture.to_string_lossy())?),
+        };
+        match manifest.get(&key) {
+            Some(known) if known != answer => {
+                return Err(format!(
+                    "Part {}: the manifest already has {} for {:?}",
+                    part, known, fixture
+                ));
+            }
+            None if args.record => {
+                manifest.insert(key, answer.clone());
+                recorded += 1;
+            }
+            _ => {}
+        }
+    }
+
+    if recorded > 0 {
+        println!("Recording {} new answers in {}", recorded, MANIFEST_FILE);
+        manifest.save(MANIFEST_FILE)?;
+    }
+    return Ok(());
+}
+
+fn run_new(args: &NewArgs) -> Result<(), String> {
+    let scaffold = scaffold::new_day(Path::new(&args.workspace), args.year, args.day)?;
+
+    println!("Created {}", scaffold.dir.display());
+    for (i, answer) in scaffold.answers.iter().enumerate() {
+        match answer {
+            Some(answer) => println!("Part {} example answer: {}", i + 1, answer),
+            None => println!(
+                "Part {} example answer: unknown, fill it in tests/example.rs",
+                i + 1
+            ),
+        }
+    }
+    return Ok(());
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => match args.day {
+            Some(day) if !args.all => run_one(&args, day),
+            _ => run_all(args.year),
+        },
+        Command::Verify(args) => run_verify(&args),
+        Command::Bench(args) => run_bench(&args),
+        Command::Fetch(args) => run_fetch(&args),
+        Command::Submit(args) => run_submit(&args),
+        Command::Extract(args) => run_extract(&args),
+        Command::New(args) => run_new(&args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
@@ -0,0 +1,276 @@
+/// One part of a saved puzzle page
+pub struct Part {
+    /// The part's description as Markdown
+    pub markdown: String,
+    /// Contents of every `<pre><code>` block, in page order
+    pub examples: Vec<String>,
+    /// Every emphasized `<code><em>` value, in page order. The last one is the example's answer.
+    pub answers: Vec<String>,
+}
+
+impl Part {
+    /// The answer the description gives for its example
+    pub fn example_answer(&self) -> Option<&str> {
+        return self.answers.last().map(|a| a.as_str());
+    }
+}
+
+/// A puzzle page saved from the Advent of Code site
+pub struct Page {
+    pub parts: Vec<Part>,
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: String, attrs: &'a str },
+    Close(String),
+}
+
+/// Splits HTML into tags and text. Comments, doctypes and scripts are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_ascii_lowercase()));
+            continue;
+        }
+        if tag.starts_with('!') {
+            continue;
+        }
+
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.trim_end_matches('/').to_ascii_lowercase();
+        if name == "script" || name == "style" {
+            let close = format!("</{}>", name);
+            rest = rest
+                .find(&close)
+                .map_or("", |end| &rest[end + close.len()..]);
+            continue;
+        }
+        tokens.push(Token::Open { name, attrs });
+    }
+
+    return tokens;
+}
+
+/// Value of `name="..."` in a tag's attributes
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    return Some(&attrs[start..end]);
+}
+
+/// Replaces the character references the site uses
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded += &rest[..start];
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+
+        match c {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded += rest;
+    return decoded;
+}
+
+/// Renders one `<article class="day-desc">`, collecting its examples and answers on the way
+fn render_article(tokens: &[Token]) -> Part {
+    let mut markdown = String::new();
+    let mut examples = Vec::new();
+    let mut answers = Vec::new();
+
+    let mut in_pre = false;
+    let mut pre_text = String::new();
+    // Inline `<code>` is buffered so that `<code><em>x</em></code>` can become **`x`**
+    let mut code: Option<(String, bool)> = None;
+    let mut links = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Text(text) if in_pre => pre_text += &decode_entities(text),
+            Token::Text(text) => {
+                let text = decode_entities(text).replace('\n', " ");
+                match &mut code {
+                    Some((buffer, _)) => *buffer += &text,
+                    None => markdown += &text,
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" => markdown += "## ",
+                "pre" => {
+                    in_pre = true;
+                    pre_text.clear();
+                }
+                "code" if !in_pre => code = Some((String::new(), false)),
+                "em" => match &mut code {
+                    Some((_, emphasized)) => *emphasized = true,
+                    None => markdown += "**",
+                },
+                "li" => markdown += "- ",
+                "a" => {
+                    links.push(attr(attrs, "href").unwrap_or("").to_string());
+                    markdown += "[";
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" => markdown += "\n\n",
+                "ul" => markdown += "\n",
+                "li" => markdown += "\n",
+                "pre" => {
+                    in_pre = false;
+                    markdown += &format!("```\n{}```\n\n", pre_text);
+                    examples.push(pre_text.clone());
+                }
+                "code" if !in_pre => {
+                    if let Some((text, emphasized)) = code.take() {
+                        if emphasized {
+                            markdown += &format!("**`{}`**", text);
+                            answers.push(text);
+                        } else {
+                            markdown += &format!("`{}`", text);
+                        }
+                    }
+                }
+                "em" if code.is_none() => markdown += "**",
+                "a" => markdown += &format!("]({})", links.pop().unwrap_or_default()),
+                _ => {}
+            },
+        }
+    }
+
+    return Part {
+        markdown: tidy(&markdown),
+        examples,
+        answers,
+    };
+}
+
+/// Drops the `---` around headings and the blank lines left between tags
+fn tidy(markdown: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_fence = false;
+
+    for line in markdown.lines() {
+        let fence = line.trim() == "```";
+        if fence {
+            in_fence = !in_fence;
+        }
+        let line = match line.trim().strip_prefix("## ") {
+            _ if in_fence && !fence => line.to_string(),
+            Some(heading) => format!("## {}", heading.trim().trim_matches('-').trim()),
+            None => line.trim().to_string(),
+        };
+
+        let blank = line.is_empty();
+        if blank && !in_fence && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    return lines.join("\n") + "\n";
+}
+
+impl Page {
+    pub fn parse(html: &str) -> Result<Page, String> {
+        let tokens = tokenize(html);
+        let mut parts = Vec::new();
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let is_description = matches!(
+                &tokens[i],
+                Token::Open { name, attrs } if name == "article" && attr(attrs, "class") == Some("day-desc")
+            );
+            if !is_description {
+                i += 1;
+                continue;
+            }
+
+            let end = tokens[i..]
+                .iter()
+                .position(|t| matches!(t, Token::Close(name) if name == "article"))
+                .map(|end| i + end)
+                .ok_or("Unterminated puzzle description")?;
+            parts.push(render_article(&tokens[i + 1..end]));
+            i = end + 1;
+        }
+
+        if parts.is_empty() {
+            return Err("No puzzle description in the page".to_string());
+        }
+        return Ok(Page { parts });
+    }
+
+    /// The whole description, with the first part's heading as the title
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for (i, part) in self.parts.iter().enumerate() {
+            if i == 0 {
+                markdown += &part.markdown.replacen("## ", "# ", 1);
+            } else {
+                markdown += "\n";
+                markdown += &part.markdown;
+            }
+        }
+        return markdown;
+    }
+}
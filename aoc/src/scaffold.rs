@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::extract;
+use crate::page::Page;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const EXAMPLE_RS: &str = include_str!("../templates/day/example.rs.tmpl");
+
+/// Fills in a template's `{{name}}` placeholders
+fn render(template: &str, values: &[(&str, &str)]) -> String {
+    let mut rendered = template.to_string();
+    for (name, value) in values {
+        rendered = rendered.replace(&format!("{{{{{}}}}}", name), value);
+    }
+    return rendered;
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    return fs::read_to_string(path).map_err(|e| format!("Unable to read {:?}: {}", path, e));
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Unable to create {:?}: {}", dir, e))?;
+    }
+    return fs::write(path, contents).map_err(|e| format!("Unable to write {:?}: {}", path, e));
+}
+
+/// Inserts `line` as the last entry of the block that starts at `start` and ends at `end`
+fn append_to_block(contents: &str, start: &str, end: &str, line: &str) -> Option<String> {
+    let block = contents.find(start)?;
+    let close = block + contents[block..].find(end)?;
+    return Some(format!(
+        "{}\n{}{}",
+        &contents[..close],
+        line,
+        &contents[close..]
+    ));
+}
+
+/// Adds a new day's crate to the workspace members, the runner's dependencies and its
+/// dispatch table
+fn register(workspace: &Path, year: u32, day: u32) -> Result<(), String> {
+    let edits = [
+        (
+            "Cargo.toml",
+            "members = [",
+            "\n]",
+            format!("    \"{}/day{}\",", year, day),
+        ),
+        (
+            "aoc/Cargo.toml",
+            "[dependencies]",
+            "\n\n",
+            format!("day{} = {{ path = \"../{}/day{}\" }}", day, year, day),
+        ),
+        (
+            "aoc/src/days.rs",
+            "pub const PUZZLES: &[Puzzle] = &[",
+            "\n];",
+            format!("    Puzzle::new::<day{0}::Day{0}>({1}, {0}),", day, year),
+        ),
+    ];
+
+    // Check every file before changing any, so a failure leaves the workspace as it was
+    let mut updated = Vec::new();
+    for (file, start, end, line) in edits {
+        let path = workspace.join(file);
+        let contents = read(&path)?;
+        if contents.contains(line.trim()) {
+            return Err(format!("{:?} already has {}", path, line.trim()));
+        }
+
+        let new_contents = append_to_block(&contents, start, end, &line)
+            .ok_or_else(|| format!("Unable to find `{}` in {:?}", start, path))?;
+        updated.push((path, new_contents));
+    }
+
+    for (path, contents) in updated {
+        write(&path, &contents)?;
+    }
+    return Ok(());
+}
+
+/// What `new_day` created
+pub struct Scaffold {
+    pub dir: PathBuf,
+    /// Example answers taken from a saved puzzle page, if there was one
+    pub answers: [Option<String>; 2],
+}
+
+/// Creates `{year}/day{day}` in `workspace` from the template and registers it. A saved
+/// puzzle page already in the directory supplies the example fixture and answers.
+pub fn new_day(workspace: &Path, year: u32, day: u32) -> Result<Scaffold, String> {
+    let dir = workspace.join(year.to_string()).join(format!("day{}", day));
+    if dir.join("Cargo.toml").exists() {
+        return Err(format!("{:?} already exists", dir));
+    }
+
+    let mut answers = [None, None];
+    if let Ok(html_path) = extract::find_page(&dir) {
+        let page = Page::parse(&read(&html_path)?)?;
+        let extracted = extract::extract(&page, &dir)?;
+
+        // The generated test only reads test.txt, so only use answers for that example
+        for (i, example) in extracted.part_examples.into_iter().enumerate() {
+            if let Some((fixture, answer)) = example {
+                if fixture.ends_with("src/test.txt") {
+                    answers[i] = Some(answer);
+                }
+            }
+        }
+    }
+
+    register(workspace, year, day)?;
+
+    let day_str = day.to_string();
+    let expected: Vec<String> = answers
+        .iter()
+        .map(|a| match a {
+            Some(a) => format!("Some({:?})", a),
+            None => "None".to_string(),
+        })
+        .collect();
+    let values = [
+        ("day", day_str.as_str()),
+        ("part1", expected[0].as_str()),
+        ("part2", expected[1].as_str()),
+    ];
+
+    write(&dir.join("Cargo.toml"), &render(CARGO_TOML, &values))?;
+    write(&dir.join("src/main.rs"), &render(MAIN_RS, &values))?;
+    write(&dir.join("src/lib.rs"), &render(LIB_RS, &values))?;
+    write(&dir.join("tests/example.rs"), &render(EXAMPLE_RS, &values))?;
+    if !dir.join("src/test.txt").exists() {
+        write(&dir.join("src/test.txt"), "")?;
+    }
+
+    return Ok(Scaffold { dir, answers });
+}
@@ -0,0 +1,291 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::Client;
+use crate::config::Config;
+
+const HEADER: &str = "\
+# Answers submitted by `aoc submit`, and what the site said about them.
+# year day part unix-time verdict answer
+";
+
+/// Shortest gap between any two submissions
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+/// Shortest gap after an answer judged wrong
+const WRONG_INTERVAL: Duration = Duration::from_secs(60);
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint which way
+    Wrong,
+    /// The answer wasn't checked because another was submitted too recently
+    RateLimited {
+        wait: Duration,
+    },
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned for a submission
+    pub fn parse(page: &str) -> Result<Verdict, String> {
+        if page.contains("That's the right answer") {
+            return Ok(Verdict::Correct);
+        }
+        if page.contains("your answer is too high") {
+            return Ok(Verdict::TooHigh);
+        }
+        if page.contains("your answer is too low") {
+            return Ok(Verdict::TooLow);
+        }
+        if page.contains("That's not the right answer") {
+            return Ok(Verdict::Wrong);
+        }
+        if page.contains("You gave an answer too recently") {
+            return Ok(Verdict::RateLimited {
+                wait: parse_wait(page).unwrap_or(Duration::from_secs(60)),
+            });
+        }
+        if page.contains("You don't seem to be solving the right level") {
+            return Err("That part is already solved, or isn't unlocked yet".to_string());
+        }
+        return Err("Unrecognised response to the submission".to_string());
+    }
+
+    /// How long to hold off submitting anything after this verdict. The site locks out
+    /// answers for a while after a wrong one, and resending sooner only lengthens that.
+    pub fn wait(&self) -> Duration {
+        match self {
+            Verdict::Correct => return MIN_INTERVAL,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => return WRONG_INTERVAL,
+            Verdict::RateLimited { wait } => return *wait,
+        }
+    }
+
+    fn to_record(&self) -> String {
+        match self {
+            Verdict::Correct => return "correct".to_string(),
+            Verdict::TooHigh => return "too-high".to_string(),
+            Verdict::TooLow => return "too-low".to_string(),
+            Verdict::Wrong => return "wrong".to_string(),
+            Verdict::RateLimited { wait } => return format!("rate-limited:{}", wait.as_secs()),
+        }
+    }
+
+    fn from_record(record: &str) -> Option<Verdict> {
+        match record {
+            "correct" => return Some(Verdict::Correct),
+            "too-high" => return Some(Verdict::TooHigh),
+            "too-low" => return Some(Verdict::TooLow),
+            "wrong" => return Some(Verdict::Wrong),
+            _ => {
+                let secs = record.strip_prefix("rate-limited:")?.parse().ok()?;
+                return Some(Verdict::RateLimited {
+                    wait: Duration::from_secs(secs),
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct"),
+            Verdict::TooHigh => write!(f, "Wrong: too high"),
+            Verdict::TooLow => write!(f, "Wrong: too low"),
+            Verdict::Wrong => write!(f, "Wrong"),
+            Verdict::RateLimited { wait } => {
+                write!(f, "Rate limited: wait {}s", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// Parses the `1m 5s` out of "You have 1m 5s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in page[start..end].split_whitespace() {
+        let unit = part.chars().last()?;
+        let number: u64 = part[..part.len() - unit.len_utf8()].parse().ok()?;
+        secs += match unit {
+            'h' => number * 3600,
+            'm' => number * 60,
+            's' => number,
+            _ => return None,
+        };
+    }
+    return Some(Duration::from_secs(secs));
+}
+
+fn now() -> u64 {
+    return SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The clock is before 1970")
+        .as_secs();
+}
+
+#[derive(Debug, Clone)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every submission made so far, so that answers the site has already judged are never resent
+pub struct SubmissionLog {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn path(config: &Config) -> PathBuf {
+        return config.inputs_dir.join("submissions.txt");
+    }
+
+    /// Loads the log for `config`. A missing file is an empty log.
+    pub fn load(config: &Config) -> Result<SubmissionLog, String> {
+        let path = SubmissionLog::path(config);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Unable to read {:?}: {}", path, e)),
+        };
+
+        let mut entries = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(6, ' ').collect();
+            let bad_entry = || {
+                format!(
+                    "{:?}:{}: expected \"year day part unix-time verdict answer\"",
+                    path,
+                    i + 1
+                )
+            };
+            if fields.len() != 6 {
+                return Err(bad_entry());
+            }
+
+            entries.push(Submission {
+                year: fields[0].parse().map_err(|_| bad_entry())?,
+                day: fields[1].parse().map_err(|_| bad_entry())?,
+                part: fields[2].parse().map_err(|_| bad_entry())?,
+                time: fields[3].parse().map_err(|_| bad_entry())?,
+                verdict: Verdict::from_record(fields[4]).ok_or_else(bad_entry)?,
+                answer: fields[5].to_string(),
+            });
+        }
+
+        return Ok(SubmissionLog { path, entries });
+    }
+
+    fn append(&mut self, submission: Submission) -> Result<(), String> {
+        self.entries.push(submission);
+
+        let mut contents = String::from(HEADER);
+        for s in &self.entries {
+            contents += &format!(
+                "{} {} {} {} {} {}\n",
+                s.year,
+                s.day,
+                s.part,
+                s.time,
+                s.verdict.to_record(),
+                s.answer
+            );
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Unable to create {:?}: {}", dir, e))?;
+        }
+        return fs::write(&self.path, contents)
+            .map_err(|e| format!("Unable to write {:?}: {}", self.path, e));
+    }
+
+    /// Why `answer` shouldn't be sent, if the log already settles it
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<(), String> {
+        let previous = self
+            .entries
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+        let value: Option<i64> = answer.parse().ok();
+
+        for s in previous {
+            let bound: Option<i64> = s.answer.parse().ok();
+            let settled = match s.verdict {
+                Verdict::Correct => {
+                    return Err(format!("Already solved: the answer was {}", s.answer))
+                }
+                Verdict::RateLimited { .. } => false,
+                _ if s.answer == answer => true,
+                Verdict::TooHigh => matches!((value, bound), (Some(v), Some(b)) if v >= b),
+                Verdict::TooLow => matches!((value, bound), (Some(v), Some(b)) if v <= b),
+                Verdict::Wrong => false,
+            };
+
+            if settled {
+                return Err(format!(
+                    "Not submitting {}: {} was already judged \"{}\"",
+                    answer, s.answer, s.verdict
+                ));
+            }
+        }
+
+        // The site's rate limit applies across every puzzle
+        if let Some(last) = self.entries.last() {
+            let (until, now) = (last.time + last.verdict.wait().as_secs(), now());
+            if until > now {
+                let reason = match last.verdict {
+                    Verdict::RateLimited { .. } => "Rate limited",
+                    _ => "Throttled",
+                };
+                return Err(format!(
+                    "{}: wait {}s before submitting again",
+                    reason,
+                    until - now
+                ));
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Submits `answer` unless the log shows it's already been judged, and records the verdict
+pub fn submit(
+    config: &Config,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let mut log = SubmissionLog::load(config)?;
+    log.check(year, day, part, answer)?;
+
+    let page = Client::new(config)?.answer(year, day, part, answer)?;
+    let verdict = Verdict::parse(&page)?;
+
+    log.append(Submission {
+        year,
+        day,
+        part,
+        time: now(),
+        verdict: verdict.clone(),
+        answer: answer.to_string(),
+    })?;
+    return Ok(verdict);
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use aoc_common::{load_input, Error, Puzzle};
+
+use crate::answers::{hash_input, AnswerKey, Manifest};
+use crate::days;
+
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// The result of solving one part of a puzzle for one input file
+pub struct Check {
+    pub key: AnswerKey,
+    pub file: PathBuf,
+    pub answer: Result<String, Error>,
+    pub outcome: Outcome,
+}
+
+/// Solves each part of `puzzle` for its input and every example, and compares
+/// the answers with the manifest.
+pub fn verify(puzzle: &Puzzle, manifest: &Manifest) -> Result<Vec<Check>, String> {
+    let mut checks = Vec::new();
+
+    for file in days::all_inputs(puzzle)? {
+        let contents = load_input(&file.to_string_lossy())?;
+        let input_hash = hash_input(&contents);
+
+        for part in [1, 2] {
+            let key = AnswerKey {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                input_hash: input_hash.clone(),
+            };
+            // Solve each part separately: an example often only fits one of them
+            let answer = (puzzle.solve)(&contents, &[part]).map(|mut a| a.remove(0));
+
+            let outcome = match (manifest.get(&key), &answer) {
+                (None, _) => Outcome::Unknown,
+                (Some(expected), Ok(answer)) if expected == answer => Outcome::Pass,
+                (Some(expected), _) => Outcome::Fail {
+                    expected: expected.to_string(),
+                },
+            };
+
+            checks.push(Check {
+                key,
+                file: file.clone(),
+                answer,
+                outcome,
+            });
+        }
+    }
+
+    return Ok(checks);
+}
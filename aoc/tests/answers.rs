@@ -0,0 +1,17 @@
+use std::process::Command;
+
+/// Every input and example with a known answer must still produce it.
+#[test]
+fn known_answers_are_unchanged() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .output()
+        .expect("Unable to run aoc");
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+fn bench(args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "--year", "2023", "--day", "1", "--iterations", "1"])
+        .args(args)
+        .output()
+        .expect("Unable to run aoc");
+}
+
+#[test]
+fn phases_with_no_baseline_timing_are_not_compared() {
+    let dir = temp_dir("bench-zero");
+    let path = dir.join("baseline.json");
+    let path = path.to_str().unwrap();
+    assert!(bench(&["--save", path]).status.success());
+
+    // A baseline phase that ran in under a nanosecond
+    let saved = fs::read_to_string(path).unwrap();
+    let mut baseline: serde_json::Value = serde_json::from_str(&saved).unwrap();
+    for result in baseline["results"].as_array_mut().unwrap() {
+        result["median_ns"] = 0.0.into();
+    }
+    fs::write(path, baseline.to_string()).unwrap();
+
+    let output = bench(&["--baseline", path]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("no baseline timing"), "{}", stdout);
+    assert!(!stdout.contains("REGRESSION"), "{}", stdout);
+}
@@ -0,0 +1,117 @@
+//! A minimal HTTP server standing in for the Advent of Code site.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// One request received by the stub
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    /// Serves every request with the status and body returned by `respond`
+    pub fn start(respond: fn(&Request) -> (u16, String)) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to bind the stub server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+
+                let (status, body) = respond(&request);
+                received.lock().unwrap().push(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        return StubServer { url, requests };
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        return self.requests.lock().unwrap().clone();
+    }
+}
+
+fn read_request(stream: &mut std::net::TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut words = line.split_whitespace();
+    let method = words.next()?.to_string();
+    let path = words.next()?.to_string();
+
+    let mut cookie = None;
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.trim().to_string()),
+            "content-length" => length = value.trim().parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    return Some(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+}
+
+/// A fresh, empty directory for one test's cache and state files
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-test-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    return dir;
+}
+
+/// Runs the aoc binary against `server`, isolated from the user's config and cache
+pub fn aoc(server: &StubServer, dir: &Path, session: Option<&str>, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(args)
+        .env("AOC_BASE_URL", &server.url)
+        .env("AOC_INPUTS_DIR", dir.join("inputs"))
+        .env("AOC_CONFIG", dir.join("config"))
+        .env_remove("AOC_SESSION");
+    if let Some(session) = session {
+        command.env("AOC_SESSION", session);
+    }
+    return command.output().expect("Unable to run aoc");
+}
@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+const PAGE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../2023/day6/Day 6 - Advent of Code 2023.html"
+);
+
+fn extract(dir: &Path) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["extract", "--day", "6", "--html", PAGE])
+        .arg("--dir")
+        .arg(dir)
+        .output()
+        .expect("Unable to run aoc");
+}
+
+/// The saved day 6 page yields Markdown, one fixture per distinct example, and both example answers.
+#[test]
+fn extracts_markdown_examples_and_answers() {
+    let dir = temp_dir("extract");
+    let output = extract(&dir);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Part 1: test.txt -> 288"), "{}", stdout);
+    assert!(stdout.contains("Part 2: test.txt -> 71503"), "{}", stdout);
+
+    let markdown = fs::read_to_string(dir.join("puzzle.md")).unwrap();
+    assert!(markdown.starts_with("# Day 6: Wait For It\n"));
+    assert!(markdown.contains("\n## Part Two\n"));
+    assert!(markdown.contains("you get **`288`** (`4` * `8` * `9`)."));
+    assert!(markdown.contains("[kerning](https://en.wikipedia.org/wiki/Kerning)"));
+
+    let example = fs::read_to_string(dir.join("src/test.txt")).unwrap();
+    assert_eq!(example, "Time:      7  15   30\nDistance:  9  40  200\n");
+    let kerned = fs::read_to_string(dir.join("src/test2.txt")).unwrap();
+    assert_eq!(kerned, "Time:      71530\nDistance:  940200\n");
+    assert!(!dir.join("src/test3.txt").exists());
+}
+
+/// A fixture that can't be read is an error, not a free name to write over.
+#[test]
+fn unreadable_fixtures_are_left_alone() {
+    let dir = temp_dir("extract-unreadable");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let binary = [0xff, 0xfe, 0x00];
+    fs::write(dir.join("src/test.txt"), binary).unwrap();
+
+    let output = extract(&dir);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unable to read"), "{}", stderr);
+    assert_eq!(fs::read(dir.join("src/test.txt")).unwrap(), binary);
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+mod common;
+
+use common::{aoc, temp_dir, Request, StubServer};
+
+fn serve_input(request: &Request) -> (u16, String) {
+    if request.path == "/2023/day/1/input" {
+        return (200, "1abc2\npqr3stu8vwx\n".to_string());
+    }
+    return (404, "Not found".to_string());
+}
+
+/// The first fetch downloads with the session cookie; later ones come from the cache.
+#[test]
+fn fetch_downloads_once_then_uses_the_cache() {
+    let server = StubServer::start(serve_input);
+    let dir = temp_dir("fetch-cache");
+
+    for _ in 0..2 {
+        let output = aoc(&server, &dir, Some("abc123"), &["fetch", "--day", "1"]);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+
+    let cached = fs::read_to_string(dir.join("inputs/2023/day1.txt")).unwrap();
+    assert_eq!(cached, "1abc2\npqr3stu8vwx\n");
+}
+
+#[test]
+fn fetch_reads_the_session_from_the_config_file() {
+    let server = StubServer::start(serve_input);
+    let dir = temp_dir("fetch-config");
+    fs::write(dir.join("config"), "# test config\nsession = fromfile\n").unwrap();
+
+    let output = aoc(&server, &dir, None, &["fetch", "--day", "1"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        server.requests()[0].cookie.as_deref(),
+        Some("session=fromfile")
+    );
+}
+
+#[test]
+fn fetch_without_a_session_fails_without_a_request() {
+    let server = StubServer::start(serve_input);
+    let dir = temp_dir("fetch-no-session");
+
+    let output = aoc(&server, &dir, None, &["fetch", "--day", "1"]);
+    assert!(!output.status.success());
+    assert!(server.requests().is_empty());
+}
+
+/// Error pages must not end up in the cache.
+#[test]
+fn failed_fetch_is_not_cached() {
+    let server = StubServer::start(serve_input);
+    let dir = temp_dir("fetch-failure");
+
+    let output = aoc(&server, &dir, Some("abc123"), &["fetch", "--day", "2"]);
+    assert!(!output.status.success());
+    assert!(!dir.join("inputs/2023/day2.txt").exists());
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+const REGISTRIES: [&str; 3] = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"];
+
+/// A copy of just the files `aoc new` edits
+fn scratch_workspace(name: &str) -> std::path::PathBuf {
+    let dir = temp_dir(name);
+    for file in REGISTRIES {
+        fs::create_dir_all(dir.join(file).parent().unwrap()).unwrap();
+        fs::copy(Path::new(WORKSPACE).join(file), dir.join(file)).unwrap();
+    }
+    return dir;
+}
+
+fn aoc_new(workspace: &Path, day: &str) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "--year", "2023", "--day", day, "--workspace"])
+        .arg(workspace)
+        .output()
+        .expect("Unable to run aoc");
+}
+
+#[test]
+fn new_day_is_created_and_registered() {
+    let workspace = scratch_workspace("new");
+    let output = aoc_new(&workspace, "11");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let day = workspace.join("2023/day11");
+    for file in [
+        "Cargo.toml",
+        "src/main.rs",
+        "src/lib.rs",
+        "src/test.txt",
+        "tests/example.rs",
+    ] {
+        assert!(day.join(file).exists(), "missing {}", file);
+    }
+    assert!(fs::read_to_string(day.join("src/lib.rs"))
+        .unwrap()
+        .contains("impl Solution for Day11"));
+
+    let read = |file: &str| fs::read_to_string(workspace.join(file)).unwrap();
+    assert!(read("Cargo.toml").contains("    \"2023/day10\",\n    \"2023/day11\",\n]"));
+    assert!(read("aoc/Cargo.toml").contains("\nday11 = { path = \"../2023/day11\" }\n"));
+    assert!(read("aoc/src/days.rs").contains("    Puzzle::new::<day11::Day11>(2023, 11),\n];"));
+
+    // A second run must not register the day twice
+    assert!(!aoc_new(&workspace, "11").status.success());
+    assert_eq!(read("Cargo.toml").matches("2023/day11").count(), 1);
+}
+
+/// A saved puzzle page in the new day's directory fills in the example and its answers.
+#[test]
+fn new_day_uses_a_saved_page() {
+    let workspace = scratch_workspace("new-page");
+    let day = workspace.join("2023/day11");
+    fs::create_dir_all(&day).unwrap();
+    fs::copy(
+        Path::new(WORKSPACE).join("2023/day6/Day 6 - Advent of Code 2023.html"),
+        day.join("page.html"),
+    )
+    .unwrap();
+
+    let output = aoc_new(&workspace, "11");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let example = fs::read_to_string(day.join("src/test.txt")).unwrap();
+    assert_eq!(example, "Time:      7  15   30\nDistance:  9  40  200\n");
+    let test = fs::read_to_string(day.join("tests/example.rs")).unwrap();
+    assert!(test.contains("[Some(\"288\"), Some(\"71503\")]"));
+}
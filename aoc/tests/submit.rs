@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::Path;
+
+mod common;
+
+use common::{aoc, temp_dir, Request, StubServer};
+
+/// Judges answers to 2023 day 1 part 1 the way the site does, with 55386 correct
+fn judge(request: &Request) -> (u16, String) {
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/2023/day/1/answer");
+
+    let answer: i64 = request
+        .body
+        .strip_prefix("level=1&answer=")
+        .and_then(|a| a.parse().ok())
+        .expect("Unexpected form");
+    let message = match answer {
+        55386 => "That's the right answer!  You are one gold star closer to restoring snow operations.",
+        a if a > 55386 => "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+        _ => "That's not the right answer; your answer is too low.",
+    };
+    return (
+        200,
+        format!("<main><article><p>{}</p></article></main>", message),
+    );
+}
+
+fn rate_limit(_: &Request) -> (u16, String) {
+    let message = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.";
+    return (
+        200,
+        format!("<main><article><p>{}</p></article></main>", message),
+    );
+}
+
+fn stdout(output: &std::process::Output) -> String {
+    return String::from_utf8_lossy(&output.stdout).into_owned();
+}
+
+/// Moves every logged submission `secs` into the past, as if that long had gone by
+fn age_log(dir: &Path, secs: u64) {
+    let path = dir.join("inputs/submissions.txt");
+    let aged: Vec<String> = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            if line.starts_with('#') {
+                return line.to_string();
+            }
+            let mut fields: Vec<String> = line.splitn(6, ' ').map(String::from).collect();
+            fields[3] = (fields[3].parse::<u64>().unwrap() - secs).to_string();
+            return fields.join(" ");
+        })
+        .collect();
+    fs::write(&path, aged.join("\n") + "\n").unwrap();
+}
+
+/// By default the answer comes from solving the day's input; once correct it is never resent.
+#[test]
+fn correct_answer_is_recorded_and_not_resubmitted() {
+    let server = StubServer::start(judge);
+    let dir = temp_dir("submit-correct");
+    let args = ["submit", "--day", "1", "--part", "1"];
+
+    let output = aoc(&server, &dir, Some("abc123"), &args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout(&output).contains("Correct"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].body, "level=1&answer=55386");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+
+    let log = fs::read_to_string(dir.join("inputs/submissions.txt")).unwrap();
+    assert!(log.contains(" correct 55386"));
+
+    let output = aoc(&server, &dir, Some("abc123"), &args);
+    assert!(!output.status.success());
+    assert_eq!(server.requests().len(), 1);
+}
+
+/// A judged wrong answer, or one the recorded bounds rule out, is refused locally.
+#[test]
+fn wrong_answers_are_never_resubmitted() {
+    let server = StubServer::start(judge);
+    let dir = temp_dir("submit-wrong");
+    let submit = |answer: &str| {
+        let args = ["submit", "--day", "1", "--part", "1", "--answer", answer];
+        return aoc(&server, &dir, Some("abc123"), &args);
+    };
+
+    let output = submit("60000");
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("too high"));
+    assert_eq!(server.requests().len(), 1);
+
+    assert!(!submit("60000").status.success());
+    assert!(!submit("70000").status.success());
+    assert_eq!(server.requests().len(), 1);
+
+    age_log(&dir, 60);
+    let output = submit("50000");
+    assert!(stdout(&output).contains("too low"));
+    assert_eq!(server.requests().len(), 2);
+
+    age_log(&dir, 60);
+    assert!(submit("55386").status.success());
+    assert_eq!(server.requests().len(), 3);
+}
+
+/// Nothing is sent until a minute after an answer was judged wrong.
+#[test]
+fn wrong_answers_hold_off_the_next_submission() {
+    let server = StubServer::start(judge);
+    let dir = temp_dir("submit-throttle");
+    let submit = |answer: &str| {
+        let args = ["submit", "--day", "1", "--part", "1", "--answer", answer];
+        return aoc(&server, &dir, Some("abc123"), &args);
+    };
+
+    assert!(stdout(&submit("60000")).contains("too high"));
+    let output = submit("50000");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert!(stderr.contains("Throttled: wait"), "{}", stderr);
+    assert_eq!(server.requests().len(), 1);
+
+    age_log(&dir, 30);
+    assert!(!submit("50000").status.success());
+    assert_eq!(server.requests().len(), 1);
+
+    age_log(&dir, 30);
+    assert!(stdout(&submit("50000")).contains("too low"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+/// After a rate-limited submission nothing is sent until the wait is over.
+#[test]
+fn rate_limit_is_respected() {
+    let server = StubServer::start(rate_limit);
+    let dir = temp_dir("submit-rate-limit");
+    let args = ["submit", "--day", "1", "--part", "1", "--answer", "12345"];
+
+    let output = aoc(&server, &dir, Some("abc123"), &args);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("Rate limited: wait 65s"));
+
+    let output = aoc(&server, &dir, Some("abc123"), &args);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Rate limited"));
+    assert_eq!(server.requests().len(), 1);
+}